@@ -0,0 +1,787 @@
+//! Persistent settings that can be loaded from and saved back to a TOML configuration file.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use regex::RegexSet;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    matrix::{Charset, Theme, DEFAULT_TEXT_SIZE},
+    names::{Name, PickMode},
+    Source,
+};
+
+/// Settings that control the look and behavior of the screensaver. Every value has a default, so a
+/// configuration file only needs to contain the values that differ.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Frames per second.
+    pub fps: u64,
+    /// Drops per second.
+    pub dps: u64,
+    /// Percentage of cells in the background that are filled with random characters.
+    pub density: u8,
+    /// Color scheme of the rain and the background.
+    pub theme: Theme,
+    /// Characters that the rain and the background are made of.
+    pub charset: Charset,
+    /// Source to fill rain drops from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    /// Additional sources that are merged with the main source.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceEntry>,
+    /// Percentage of the screen's width or height that the countdown and messages fill.
+    pub text_size: u8,
+    /// FIGlet (`.flf`) or BDF (`.bdf`) font file to draw ASCII-Art text with, instead of the
+    /// built-in font.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font: Option<PathBuf>,
+    /// Playlist file with a sequence of scenes to run at startup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playlist: Option<PathBuf>,
+    /// Countdown related settings.
+    pub countdown: CountdownConfig,
+    /// Intro sequence shown before the rain starts.
+    pub intro: IntroConfig,
+    /// Scrolling ticker with announcements.
+    pub ticker: TickerConfig,
+    /// Image that is gradually revealed by the rain.
+    pub reveal: RevealConfig,
+    /// How names are picked and styled.
+    pub names: NamesConfig,
+    /// Reactions to events of a Twitch channel.
+    #[cfg(feature = "twitch")]
+    pub events: EventsConfig,
+}
+
+/// Additional source of names, with its own weight and style.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SourceEntry {
+    #[serde(flatten)]
+    pub source: Source,
+    /// Weight of all names from this source, compared to the weight of 1 for other names.
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    /// Color of the names from this source, as index into the 256 color palette.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<u8>,
+}
+
+impl SourceEntry {
+    /// Apply the weight and color of this source to its freshly loaded names. Only alerts keep
+    /// their own color.
+    pub fn apply(&self, names: Vec<Name>) -> Vec<Name> {
+        names
+            .into_iter()
+            .map(|name| Name {
+                weight: name.weight.saturating_mul(self.weight),
+                color: if name.alert {
+                    name.color
+                } else {
+                    self.color.or(name.color)
+                },
+                ..name
+            })
+            .collect()
+    }
+}
+
+impl From<Source> for SourceEntry {
+    fn from(source: Source) -> Self {
+        Self {
+            source,
+            weight: default_weight(),
+            color: None,
+        }
+    }
+}
+
+const fn default_weight() -> u32 {
+    1
+}
+
+/// Settings for the countdown.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CountdownConfig {
+    /// Actions that are run in order, once the countdown reaches zero.
+    pub on_finish: Vec<FinishAction>,
+}
+
+/// Settings for the typewriter intro sequence.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IntroConfig {
+    /// Whether to show the intro at startup.
+    pub enabled: bool,
+    /// Lines to type out, one after another.
+    pub lines: Vec<String>,
+    /// Text file to read the lines from instead, with one line per line of text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// Time to type a single character, in milliseconds.
+    pub char_delay: u64,
+    /// Time a fully typed line stays on screen before the next one starts, in milliseconds.
+    pub line_pause: u64,
+}
+
+impl Default for IntroConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lines: [
+                "Wake up, Neo...",
+                "The Matrix has you...",
+                "Follow the white rabbit.",
+                "Knock, knock, Neo.",
+            ]
+            .map(ToOwned::to_owned)
+            .to_vec(),
+            file: None,
+            char_delay: 120,
+            line_pause: 2500,
+        }
+    }
+}
+
+impl IntroConfig {
+    /// Lines of the intro script, either from the configured file or the inline list.
+    pub fn load_lines(&self) -> Result<Vec<String>> {
+        match &self.file {
+            Some(path) => Ok(fs::read_to_string(path)
+                .with_context(|| format!("failed reading intro from {}", path.display()))?
+                .lines()
+                .map(ToOwned::to_owned)
+                .collect()),
+            None => Ok(self.lines.clone()),
+        }
+    }
+}
+
+/// Settings for the scrolling ticker.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TickerConfig {
+    /// Edge of the screen the ticker is shown at.
+    pub position: TickerPosition,
+    /// Scroll speed in cells per second.
+    pub speed: u16,
+    /// Announcements to scroll through in order, like sponsors or social links. They're shown
+    /// before the messages of the source, and the ticker is hidden if there are none at all.
+    pub messages: Vec<String>,
+    /// Source to load additional messages from, like a text file with one message per line. It's
+    /// kept up to date in the background like the sources of names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+}
+
+impl Default for TickerConfig {
+    fn default() -> Self {
+        Self {
+            position: TickerPosition::default(),
+            speed: 10,
+            messages: Vec::new(),
+            source: None,
+        }
+    }
+}
+
+/// Edge of the screen the ticker is shown at.
+#[derive(Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TickerPosition {
+    Top,
+    #[default]
+    Bottom,
+}
+
+/// Settings for revealing an image through the rain.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RevealConfig {
+    /// PNG or JPEG image to reveal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<PathBuf>,
+    /// Minimum brightness of a part of the image to be revealed, from 0 to 255.
+    pub threshold: u8,
+}
+
+impl Default for RevealConfig {
+    fn default() -> Self {
+        Self {
+            image: None,
+            threshold: 128,
+        }
+    }
+}
+
+/// Settings for reacting to follows, subscriptions, raids and cheers of a Twitch channel, which are
+/// received through EventSub.
+#[cfg(feature = "twitch")]
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EventsConfig {
+    /// Whether to connect to EventSub at all.
+    pub enabled: bool,
+    /// WebSocket address of EventSub. Set to `ws://127.0.0.1:8080/ws` to test with the mock server
+    /// of the Twitch CLI.
+    pub url: String,
+    /// Base address of the Twitch API, that events are subscribed with. Set to
+    /// `http://127.0.0.1:8080` for the mock server of the Twitch CLI.
+    pub api_url: String,
+    /// Client ID of the application that the token was created for.
+    pub client_id: String,
+    /// User access token of the broadcaster, with the scopes `moderator:read:followers`,
+    /// `channel:read:subscriptions` and `bits:read`.
+    pub token: String,
+    /// User ID of the channel to react to.
+    pub broadcaster_id: String,
+    /// Upper limit of drops that fall for a single raid or cheer.
+    pub max_storm: u16,
+}
+
+#[cfg(feature = "twitch")]
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: "wss://eventsub.wss.twitch.tv/ws".to_owned(),
+            api_url: "https://api.twitch.tv/helix".to_owned(),
+            client_id: String::new(),
+            token: String::new(),
+            broadcaster_id: String::new(),
+            max_storm: 60,
+        }
+    }
+}
+
+/// Settings for picking names from the list.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NamesConfig {
+    /// Names that are highlighted, like subscribers or moderators.
+    pub highlight: Vec<String>,
+    /// Prefix that marks a name as highlighted, like `*` for a `*name` line in a text file. The
+    /// prefix is removed from the name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_prefix: Option<String>,
+    /// Factor that the weight of highlighted names is multiplied with.
+    pub highlight_weight: u32,
+    /// Weights of individual names, overriding the default weight.
+    pub weights: BTreeMap<String, u32>,
+    /// Avoid picking the same name twice in a row.
+    pub no_repeat: bool,
+    /// Strategy to pick the next name with.
+    pub mode: PickMode,
+    /// Show how many names were shown in the current round of the shuffle mode.
+    pub show_progress: bool,
+    /// Names to use if there is no source or the source didn't return any names. If this is empty
+    /// as well, the rain consists of random characters only.
+    pub fallback: Vec<String>,
+    /// Clean-up and filtering of loaded names.
+    pub filter: FilterConfig,
+}
+
+impl Default for NamesConfig {
+    fn default() -> Self {
+        Self {
+            highlight: Vec::new(),
+            highlight_prefix: None,
+            highlight_weight: 3,
+            weights: BTreeMap::new(),
+            no_repeat: false,
+            mode: PickMode::default(),
+            show_progress: false,
+            fallback: Vec::new(),
+            filter: FilterConfig::default(),
+        }
+    }
+}
+
+impl NamesConfig {
+    /// Apply the filters, highlights and weights to a freshly loaded list of names.
+    pub fn apply(&self, names: Vec<Name>) -> Result<Vec<Name>> {
+        let allow = RegexSet::new(&self.filter.allow).context("invalid allow pattern")?;
+        let deny = RegexSet::new(&self.filter.deny).context("invalid deny pattern")?;
+        let mut seen = HashSet::new();
+
+        Ok(names
+            .into_iter()
+            .filter_map(|mut name| {
+                self.filter.clean(&mut name.text);
+
+                if let Some(text) = self
+                    .highlight_prefix
+                    .as_deref()
+                    .filter(|prefix| !prefix.is_empty())
+                    .and_then(|prefix| name.text.strip_prefix(prefix))
+                {
+                    name.text = text.to_owned();
+                    name.highlight = true;
+                }
+                if self.highlight.contains(&name.text) {
+                    name = name.highlighted();
+                }
+                if name.highlight {
+                    name.weight = name.weight.saturating_mul(self.highlight_weight);
+                }
+                if let Some(weight) = self.weights.get(&name.text) {
+                    name.weight = *weight;
+                }
+
+                if name.text.is_empty()
+                    || (!allow.is_empty() && !allow.is_match(&name.text))
+                    || deny.is_match(&name.text)
+                {
+                    return None;
+                }
+
+                self.filter.format(&mut name.text);
+
+                (!self.filter.dedupe || seen.insert(name.text.clone())).then_some(name)
+            })
+            .collect())
+    }
+}
+
+/// Clean-up steps applied to every loaded name, in the order of the fields. Names that end up empty
+/// are removed.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FilterConfig {
+    /// Remove leading and trailing whitespace.
+    pub trim: bool,
+    /// Remove control characters, like terminal escape sequences.
+    pub strip_control: bool,
+    /// Regular expressions of which a name must match at least one, if any are given.
+    pub allow: Vec<String>,
+    /// Regular expressions of names to remove, like offensive words.
+    pub deny: Vec<String>,
+    /// Change the case of names.
+    pub case: Case,
+    /// Maximum amount of characters of a name. Longer names are cut off and end in the ellipsis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    /// Text that marks a name as cut off.
+    pub ellipsis: String,
+    /// Remove repeated names within each source, only keeping the first one. Off by default, as
+    /// repeating a name in a file is a way to make it show up more often.
+    pub dedupe: bool,
+}
+
+impl Default for FilterConfig {
+    fn default() -> Self {
+        Self {
+            trim: false,
+            strip_control: true,
+            allow: Vec::new(),
+            deny: Vec::new(),
+            case: Case::default(),
+            max_length: None,
+            ellipsis: "…".to_owned(),
+            dedupe: false,
+        }
+    }
+}
+
+/// Case transformation of names.
+#[derive(Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Case {
+    /// Keep the names as they are.
+    #[default]
+    Keep,
+    /// Turn all letters into uppercase, like `NEO`.
+    Upper,
+    /// Turn all letters into lowercase, like `neo`.
+    Lower,
+}
+
+impl FilterConfig {
+    /// Remove whitespace and control characters.
+    fn clean(&self, text: &mut String) {
+        if self.trim {
+            *text = text.trim().to_owned();
+        }
+        if self.strip_control {
+            text.retain(|c| !c.is_control());
+        }
+    }
+
+    /// Change the case and cut off long names.
+    fn format(&self, text: &mut String) {
+        match self.case {
+            Case::Keep => {}
+            Case::Upper => *text = text.to_uppercase(),
+            Case::Lower => *text = text.to_lowercase(),
+        }
+
+        if let Some(max) = self.max_length {
+            if text.chars().count() > max {
+                let ellipsis = self.ellipsis.chars().count().min(max);
+                *text = text
+                    .chars()
+                    .take(max - ellipsis)
+                    .chain(self.ellipsis.chars().take(ellipsis))
+                    .collect();
+            }
+        }
+    }
+}
+
+/// Action to run when the countdown finishes.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishAction {
+    /// Flash the whole screen for a few seconds.
+    Flash,
+    /// Ring the terminal bell.
+    Bell,
+    /// Replace the countdown with a message.
+    Message(String),
+    /// Replace the countdown with a message that is spelled out by the rain.
+    Spell(String),
+    /// Run a shell command in the background.
+    Command(String),
+    /// Hide the countdown and go back to showing the plain rain.
+    Rain,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            fps: 5,
+            dps: 3,
+            density: 5,
+            theme: Theme::default(),
+            charset: Charset::default(),
+            source: None,
+            sources: Vec::new(),
+            text_size: DEFAULT_TEXT_SIZE,
+            font: None,
+            playlist: None,
+            countdown: CountdownConfig::default(),
+            intro: IntroConfig::default(),
+            ticker: TickerConfig::default(),
+            reveal: RevealConfig::default(),
+            names: NamesConfig::default(),
+            #[cfg(feature = "twitch")]
+            events: EventsConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Default location of the configuration file, which is `rsmatrix/config.toml` within the
+    /// user's configuration directory.
+    pub fn default_path() -> Option<PathBuf> {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|dir| dir.join("rsmatrix/config.toml"))
+    }
+
+    /// Load the configuration from the given file, falling back to the defaults if the file
+    /// doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed reading config from {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("failed parsing config from {}", path.display()))
+    }
+
+    /// Save the configuration to the given file, creating any missing parent directories.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("failed writing config to {}", path.display()))
+    }
+}
+
+/// Single entry of the settings screen, that maps to one of the values in the [`Config`].
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Setting {
+    Theme,
+    Charset,
+    Fps,
+    Dps,
+    Density,
+    TextSize,
+    Source,
+    Save,
+}
+
+impl Setting {
+    /// All settings in the order they're shown on screen.
+    pub const ALL: [Self; 8] = [
+        Self::Theme,
+        Self::Charset,
+        Self::Fps,
+        Self::Dps,
+        Self::Density,
+        Self::TextSize,
+        Self::Source,
+        Self::Save,
+    ];
+
+    /// Human readable label of the setting.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Theme => "Theme",
+            Self::Charset => "Charset",
+            Self::Fps => "Frames/s",
+            Self::Dps => "Drops/s",
+            Self::Density => "Density",
+            Self::TextSize => "Text size",
+            Self::Source => "Source",
+            Self::Save => "Save",
+        }
+    }
+
+    /// Current value of the setting formatted for display.
+    pub fn value(self, config: &Config) -> String {
+        match self {
+            Self::Theme => format!("◂ {} ▸", config.theme.name()),
+            Self::Charset => format!("◂ {} ▸", config.charset.name()),
+            Self::Fps => format!("◂ {} ▸", config.fps),
+            Self::Dps => format!("◂ {} ▸", config.dps),
+            Self::Density => format!("◂ {}% ▸", config.density),
+            Self::TextSize => format!("◂ {}% ▸", config.text_size),
+            Self::Source => config
+                .source
+                .as_ref()
+                .map_or_else(|| "none".to_owned(), ToString::to_string),
+            Self::Save => "write to config file".to_owned(),
+        }
+    }
+
+    /// Increase or decrease a numeric setting by the given amount, keeping it within sane limits,
+    /// or cycle through the choices of a setting. Other settings are not affected.
+    pub fn adjust(self, config: &mut Config, delta: i64) {
+        let clamp =
+            |value: u64, min: u64, max: u64| value.saturating_add_signed(delta).clamp(min, max);
+
+        match self {
+            Self::Theme => config.theme = cycle(&Theme::ALL, config.theme, delta),
+            Self::Charset => config.charset = cycle(&Charset::ALL, config.charset, delta),
+            Self::Fps => config.fps = clamp(config.fps, 1, 60),
+            Self::Dps => config.dps = clamp(config.dps, 1, 60),
+            Self::Density => config.density = clamp(config.density.into(), 0, 50) as u8,
+            Self::TextSize => {
+                config.text_size = (config.text_size as i64 + delta * 5).clamp(10, 100) as u8;
+            }
+            Self::Source | Self::Save => {}
+        }
+    }
+}
+
+/// Move the given amount of steps from the current choice, wrapping around at either end.
+fn cycle<T: Copy + PartialEq>(choices: &[T], current: T, delta: i64) -> T {
+    let index = choices.iter().position(|c| *c == current).unwrap_or(0);
+    let index = (index as i64 + delta).rem_euclid(choices.len() as i64);
+    choices[index as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, FinishAction, Setting};
+    use crate::{names::Name, Source};
+
+    #[test]
+    fn roundtrip() {
+        let config = Config {
+            source: Some(Source::File {
+                path: "names.txt".into(),
+            }),
+            ..Config::default()
+        };
+
+        let content = toml::to_string_pretty(&config).unwrap();
+        let config = toml::from_str::<Config>(&content).unwrap();
+
+        assert_eq!(
+            Some("file:names.txt".to_owned()),
+            config.source.map(|s| s.to_string())
+        );
+    }
+
+    #[test]
+    fn cycle_choices() {
+        let mut config = toml::from_str::<Config>("theme = 'mono'\ncharset = 'binary'").unwrap();
+
+        Setting::Theme.adjust(&mut config, 1);
+        Setting::Charset.adjust(&mut config, -1);
+        assert_eq!("◂ green ▸", Setting::Theme.value(&config));
+        assert_eq!("◂ latin ▸", Setting::Charset.value(&config));
+    }
+
+    #[test]
+    fn finish_actions() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [countdown]
+            on_finish = ["flash", { message = "LIVE" }, { command = "true" }, "rain"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            config.countdown.on_finish.as_slice(),
+            [
+                FinishAction::Flash,
+                FinishAction::Message(message),
+                FinishAction::Command(_),
+                FinishAction::Rain,
+            ] if message == "LIVE"
+        ));
+    }
+
+    #[test]
+    fn name_highlights() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [names]
+            highlight = ["mod"]
+            highlight_prefix = "*"
+            weights = { heavy = 7 }
+            "#,
+        )
+        .unwrap();
+
+        let names = ["*sub", "mod", "heavy", "viewer"]
+            .map(|name| Name::new(name.to_owned()))
+            .to_vec();
+        let names = config
+            .names
+            .apply(names)
+            .unwrap()
+            .into_iter()
+            .map(|name| (name.text, name.weight, name.highlight))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("sub".to_owned(), 3, true),
+                ("mod".to_owned(), 3, true),
+                ("heavy".to_owned(), 7, false),
+                ("viewer".to_owned(), 1, false),
+            ],
+            names
+        );
+
+        // Highlighting by prefix keeps the rest of the name as loaded.
+        let alert = Name {
+            weight: 2,
+            ..Name::new("*smith".to_owned()).alert(196)
+        };
+        let names = config.names.apply(vec![alert]).unwrap();
+        assert_eq!(
+            vec![Name {
+                weight: 6,
+                highlight: true,
+                ..Name::new("smith".to_owned()).alert(196)
+            }],
+            names
+        );
+    }
+
+    #[test]
+    fn name_filters() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [names.filter]
+            trim = true
+            dedupe = true
+            deny = ["(?i)badword"]
+            case = "upper"
+            max_length = 6
+            "#,
+        )
+        .unwrap();
+
+        let names = [
+            "  alice ",
+            "bob\x07",
+            "",
+            "xBadWordx",
+            "bartholomew",
+            "ALICE",
+        ]
+        .map(|name| Name::new(name.to_owned()))
+        .to_vec();
+        let names = config
+            .names
+            .apply(names)
+            .unwrap()
+            .into_iter()
+            .map(|name| name.text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["ALICE", "BOB", "BARTH…"], names);
+
+        let config = toml::from_str::<Config>("names.filter.allow = ['(']").unwrap();
+        assert!(config.names.apply(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn multiple_sources() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [[sources]]
+            type = "file"
+            path = "subs.txt"
+            weight = 3
+            color = 220
+
+            [[sources]]
+            type = "list"
+            names = ["neo", "trinity"]
+            "#,
+        )
+        .unwrap();
+
+        let sources = config
+            .sources
+            .iter()
+            .map(|entry| (entry.source.to_string(), entry.weight, entry.color))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("file:subs.txt".to_owned(), 3, Some(220)),
+                ("list:neo,trinity".to_owned(), 1, None),
+            ],
+            sources
+        );
+
+        let names = config.sources[0].apply(vec![
+            Name::new("neo".to_owned()),
+            Name {
+                color: Some(33),
+                ..Name::new("trinity".to_owned())
+            },
+            Name::new("smith".to_owned()).alert(196),
+        ]);
+        assert_eq!(
+            vec![(3, Some(220)), (3, Some(220)), (3, Some(196))],
+            names
+                .into_iter()
+                .map(|name| (name.weight, name.color))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Targets of countdowns, that are given as a point in time instead of a duration.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveTime, TimeZone};
+
+/// Parse the target of a countdown, which is either an RFC 3339 timestamp or a time of the day.
+pub fn parse_target(value: &str) -> Result<DateTime<Local>> {
+    resolve_target(value, Local::now())
+}
+
+/// Resolve the target of a countdown relative to the given current time. A time of the day refers
+/// to the next time it occurs, which is either today or tomorrow.
+fn resolve_target(value: &str, now: DateTime<Local>) -> Result<DateTime<Local>> {
+    if let Ok(target) = DateTime::parse_from_rfc3339(value) {
+        return Ok(target.with_timezone(&Local));
+    }
+
+    let Ok(time) = NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+    else {
+        bail!("expected a time like `20:00` or an RFC 3339 timestamp");
+    };
+
+    let mut date = now.date_naive();
+    if time <= now.time() {
+        date = date.succ_opt().unwrap_or(date);
+    }
+
+    match Local.from_local_datetime(&date.and_time(time)).earliest() {
+        Some(target) => Ok(target),
+        None => bail!("{value} doesn't exist in the local time zone on {date}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+
+    use super::resolve_target;
+
+    #[test]
+    fn countdown_target() {
+        let now = Local.with_ymd_and_hms(2024, 6, 30, 18, 30, 0).unwrap();
+
+        assert_eq!(
+            Local.with_ymd_and_hms(2024, 6, 30, 20, 0, 0).unwrap(),
+            resolve_target("20:00", now).unwrap()
+        );
+        assert_eq!(
+            Local.with_ymd_and_hms(2024, 7, 1, 8, 15, 30).unwrap(),
+            resolve_target("08:15:30", now).unwrap()
+        );
+        assert_eq!(
+            "2024-06-30T18:00:00+00:00",
+            resolve_target("2024-06-30T20:00:00+02:00", now)
+                .unwrap()
+                .to_utc()
+                .to_rfc3339()
+        );
+        assert!(resolve_target("tomorrow", now).is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//! Twitch EventSub client, that receives follows, subscriptions, raids and cheers of a channel
+//! over a WebSocket connection.
+
+use std::{
+    net::TcpStream,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+use serde_json::json;
+use tungstenite::{stream::MaybeTlsStream, Message, WebSocket};
+
+use crate::{config::EventsConfig, sources::backoff};
+
+/// Time without any message after which the connection is considered lost. Twitch sends keepalive
+/// messages every 10 seconds by default.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Something that happened in the channel, or to the connection.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ChannelEvent {
+    /// Connected to EventSub and subscribed to all events.
+    Connected,
+    /// A user followed the channel.
+    Follow(String),
+    /// A user subscribed to the channel.
+    Subscribe(String),
+    /// Another channel raided with the given amount of viewers.
+    Raid { from: String, viewers: u32 },
+    /// A user, or an anonymous one, cheered with the given amount of bits.
+    Cheer { name: String, bits: u32 },
+    /// The connection failed and is retried, or subscribing to events failed.
+    Error(String),
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    metadata: Metadata,
+    payload: Payload,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    message_type: String,
+}
+
+#[derive(Deserialize)]
+struct Payload {
+    session: Option<Session>,
+    subscription: Option<Subscription>,
+    event: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Session {
+    id: String,
+    reconnect_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Subscription {
+    #[serde(rename = "type")]
+    kind: String,
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct UserEvent {
+    user_name: String,
+}
+
+#[derive(Deserialize)]
+struct RaidEvent {
+    from_broadcaster_user_name: String,
+    viewers: u32,
+}
+
+#[derive(Deserialize)]
+struct CheerEvent {
+    user_name: Option<String>,
+    bits: u32,
+}
+
+/// Connect to EventSub in the background and keep the connection alive, reconnecting with growing
+/// delays if it fails.
+pub fn listen(config: EventsConfig) -> Receiver<ChannelEvent> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut url = config.url.clone();
+        let mut subscribed = false;
+        let mut failures = 0;
+
+        loop {
+            match session(&config, &url, subscribed, &tx) {
+                // Twitch asked to move to another server, that keeps all subscriptions.
+                Ok(Some(reconnect_url)) => {
+                    url = reconnect_url;
+                    subscribed = true;
+                    failures = 0;
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    if tx.send(ChannelEvent::Error(format!("{e:#}"))).is_err() {
+                        break;
+                    }
+
+                    url.clone_from(&config.url);
+                    subscribed = false;
+                    failures += 1;
+                    thread::sleep(backoff(failures));
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+/// Run a single connection until it fails. Returns the URL to reconnect to if Twitch asks for it,
+/// or nothing if nobody listens to the events anymore.
+fn session(
+    config: &EventsConfig,
+    url: &str,
+    subscribed: bool,
+    tx: &Sender<ChannelEvent>,
+) -> Result<Option<String>> {
+    let (mut socket, _) =
+        tungstenite::connect(url).with_context(|| format!("failed connecting to {url}"))?;
+    set_read_timeout(&socket)?;
+
+    loop {
+        let text = match socket.read().context("connection to EventSub lost")? {
+            Message::Text(text) => text,
+            Message::Close(_) => bail!("EventSub closed the connection"),
+            _ => continue,
+        };
+        let message =
+            serde_json::from_str::<Envelope>(&text).context("invalid message from EventSub")?;
+
+        let event = match message.metadata.message_type.as_str() {
+            "session_welcome" => {
+                let session = message.payload.session.context("welcome without session")?;
+                if subscribed {
+                    continue;
+                }
+                match subscribe(config, &session.id) {
+                    Ok(()) => ChannelEvent::Connected,
+                    Err(e) => ChannelEvent::Error(format!("{e:#}")),
+                }
+            }
+            "session_reconnect" => {
+                let url = message
+                    .payload
+                    .session
+                    .and_then(|session| session.reconnect_url)
+                    .context("reconnect without URL")?;
+                return Ok(Some(url));
+            }
+            "notification" => {
+                let (Some(subscription), Some(event)) =
+                    (message.payload.subscription, message.payload.event)
+                else {
+                    continue;
+                };
+                match parse_event(&subscription.kind, event) {
+                    Some(event) => event,
+                    None => continue,
+                }
+            }
+            "revocation" => {
+                let subscription = message
+                    .payload
+                    .subscription
+                    .context("revocation without subscription")?;
+                ChannelEvent::Error(format!(
+                    "subscription to {} was revoked ({})",
+                    subscription.kind, subscription.status
+                ))
+            }
+            _ => continue,
+        };
+
+        if tx.send(event).is_err() {
+            return Ok(None);
+        }
+    }
+}
+
+/// Notice lost connections that don't close properly, by expecting at least the regular keepalive
+/// messages.
+fn set_read_timeout(socket: &WebSocket<MaybeTlsStream<TcpStream>>) -> Result<()> {
+    match socket.get_ref() {
+        MaybeTlsStream::Plain(stream) => stream.set_read_timeout(Some(READ_TIMEOUT))?,
+        MaybeTlsStream::Rustls(stream) => stream.sock.set_read_timeout(Some(READ_TIMEOUT))?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Subscribe the session to all events of the channel that are reacted to. Each subscription is
+/// attempted, even if others fail, like for a token without the scope of one of them.
+fn subscribe(config: &EventsConfig, session_id: &str) -> Result<()> {
+    let url = format!(
+        "{}/eventsub/subscriptions",
+        config.api_url.trim_end_matches('/')
+    );
+    let id = &config.broadcaster_id;
+    let subscriptions = [
+        (
+            "channel.follow",
+            "2",
+            json!({ "broadcaster_user_id": id, "moderator_user_id": id }),
+        ),
+        (
+            "channel.subscribe",
+            "1",
+            json!({ "broadcaster_user_id": id }),
+        ),
+        ("channel.raid", "1", json!({ "to_broadcaster_user_id": id })),
+        ("channel.cheer", "1", json!({ "broadcaster_user_id": id })),
+    ];
+
+    let failures = subscriptions
+        .into_iter()
+        .filter_map(|(kind, version, condition)| {
+            ureq::post(&url)
+                .set("Client-Id", &config.client_id)
+                .set("Authorization", &format!("Bearer {}", config.token))
+                .send_json(json!({
+                    "type": kind,
+                    "version": version,
+                    "condition": condition,
+                    "transport": { "method": "websocket", "session_id": session_id },
+                }))
+                .err()
+                .map(|e| format!("{kind} ({e})"))
+        })
+        .collect::<Vec<_>>();
+
+    ensure!(
+        failures.is_empty(),
+        "failed subscribing to {}",
+        failures.join(", ")
+    );
+    Ok(())
+}
+
+/// Turn the payload of a notification into an event, if it's one of the supported types.
+fn parse_event(kind: &str, event: serde_json::Value) -> Option<ChannelEvent> {
+    match kind {
+        "channel.follow" => serde_json::from_value::<UserEvent>(event)
+            .ok()
+            .map(|e| ChannelEvent::Follow(e.user_name)),
+        "channel.subscribe" => serde_json::from_value::<UserEvent>(event)
+            .ok()
+            .map(|e| ChannelEvent::Subscribe(e.user_name)),
+        "channel.raid" => {
+            serde_json::from_value::<RaidEvent>(event)
+                .ok()
+                .map(|e| ChannelEvent::Raid {
+                    from: e.from_broadcaster_user_name,
+                    viewers: e.viewers,
+                })
+        }
+        "channel.cheer" => {
+            serde_json::from_value::<CheerEvent>(event)
+                .ok()
+                .map(|e| ChannelEvent::Cheer {
+                    name: e.user_name.unwrap_or_else(|| "Anonymous".to_owned()),
+                    bits: e.bits,
+                })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, thread, time::Duration};
+
+    use serde_json::json;
+    use tungstenite::Message;
+
+    use super::{listen, ChannelEvent};
+    use crate::config::EventsConfig;
+
+    #[test]
+    fn mock_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+
+            let mut send = |message_type: &str, payload: serde_json::Value| {
+                let message = json!({
+                    "metadata": { "message_type": message_type },
+                    "payload": payload,
+                });
+                socket.send(Message::Text(message.to_string())).unwrap();
+            };
+
+            send("session_welcome", json!({ "session": { "id": "abc" } }));
+            send("session_keepalive", json!({}));
+            send(
+                "notification",
+                json!({
+                    "subscription": { "type": "channel.follow", "status": "enabled" },
+                    "event": { "user_name": "neo" },
+                }),
+            );
+            send(
+                "notification",
+                json!({
+                    "subscription": { "type": "channel.raid", "status": "enabled" },
+                    "event": { "from_broadcaster_user_name": "morpheus", "viewers": 42 },
+                }),
+            );
+            send(
+                "notification",
+                json!({
+                    "subscription": { "type": "channel.cheer", "status": "enabled" },
+                    "event": { "user_name": null, "bits": 100 },
+                }),
+            );
+            thread::sleep(Duration::from_millis(200));
+        });
+
+        // Nothing listens at the API, so subscribing fails without ending the connection.
+        let events = listen(EventsConfig {
+            url,
+            api_url: "http://127.0.0.1:1".to_owned(),
+            ..EventsConfig::default()
+        });
+        let next = || events.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        // Every subscription is attempted, and all failures are reported together.
+        let ChannelEvent::Error(e) = next() else {
+            panic!("expected the subscriptions to fail");
+        };
+        for kind in [
+            "channel.follow",
+            "channel.subscribe",
+            "channel.raid",
+            "channel.cheer",
+        ] {
+            assert!(e.contains(kind), "{kind} missing in {e:?}");
+        }
+        assert_eq!(ChannelEvent::Follow("neo".to_owned()), next());
+        assert_eq!(
+            ChannelEvent::Raid {
+                from: "morpheus".to_owned(),
+                viewers: 42
+            },
+            next()
+        );
+        assert_eq!(
+            ChannelEvent::Cheer {
+                name: "Anonymous".to_owned(),
+                bits: 100
+            },
+            next()
+        );
+
+        server.join().unwrap();
+    }
+}
@@ -1,17 +1,22 @@
 #![forbid(unsafe_code)]
 #![deny(rust_2018_idioms, clippy::all)]
 #![warn(clippy::nursery)]
+// Checks with `% n == 0` are kept throughout instead of `is_multiple_of`, which needs Rust 1.87.
+#![allow(clippy::manual_is_multiple_of)]
 
 use std::{
-    fs::File,
-    io::{self, prelude::*, BufReader},
-    path::PathBuf,
+    fmt::{self, Display},
+    io::{self, prelude::*},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    str::FromStr,
     sync::mpsc::{self, Receiver},
     thread,
     time::{Duration, Instant},
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, TimeDelta, Timelike};
 use clap::{Parser, Subcommand, ValueHint};
 use crossterm::{
     event::{self, Event, KeyCode, KeyModifiers},
@@ -22,33 +27,79 @@ use matrix::{KanaBackground, KanaBackgroundState};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
     layout::{Margin, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::{Clear, Paragraph},
     Terminal,
 };
+use serde::{Deserialize, Serialize};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::matrix::{
-    Countdown, KanaBorder, KanaBorderState, KanaList, KanaListState, Rain, RainState,
+#[cfg(feature = "twitch")]
+use crate::events::ChannelEvent;
+use crate::{
+    config::{Config, FinishAction, Setting, SourceEntry, TickerConfig, TickerPosition},
+    matrix::{
+        BigText, Countdown, Dissolve, Font, KanaBorder, KanaBorderState, KanaForm, KanaFormState,
+        KanaList, KanaListState, ListItem, Picture, Rain, RainState, Reveal, RevealState, Spell,
+        SpellState, Ticker, TickerState, Typewriter, TypewriterState, DEFAULT_SPELL_HOLD,
+    },
+    names::{Name, PickMode},
+    playlist::{Playlist, Scene, Transition},
+    sources::{GitField, Watcher},
+    stopwatch::Stopwatch,
 };
 
-mod matrix;
+mod config;
+mod countdown;
 #[cfg(feature = "twitch")]
-mod twitch;
+mod events;
+mod matrix;
+mod names;
+mod playlist;
+mod sources;
+mod stopwatch;
 
 #[derive(Parser)]
 #[command(about, author, version)]
 struct Args {
-    /// Frames per second.
-    #[arg(short, long, default_value_t = 5)]
-    fps: u64,
-    /// Drops per second.
-    #[arg(short, long, default_value_t = 3)]
-    dps: u64,
+    /// Location of the configuration file. Defaults to `rsmatrix/config.toml` in the user's
+    /// configuration directory.
+    #[arg(short, long, value_hint = ValueHint::FilePath)]
+    config: Option<PathBuf>,
+    /// Frames per second [default: 5].
+    #[arg(short, long)]
+    fps: Option<u64>,
+    /// Drops per second [default: 3].
+    #[arg(short, long)]
+    dps: Option<u64>,
+    /// Immediately start a countdown to the given time of the day (like `20:00`) or RFC 3339
+    /// timestamp (like `2024-06-30T20:00:00+02:00`). Times of the day that already passed refer to
+    /// the next day.
+    #[arg(long, value_name = "TIME", value_parser = countdown::parse_target)]
+    countdown_until: Option<DateTime<Local>>,
+    /// Show the intro sequence before the rain starts, as configured in the config file.
+    #[arg(long)]
+    intro: bool,
+    /// Playlist file with a sequence of scenes to run, like an intro followed by a countdown.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    playlist: Option<PathBuf>,
+    /// PNG or JPEG image that is gradually revealed by the rain.
+    #[arg(long, value_name = "IMAGE", value_hint = ValueHint::FilePath)]
+    reveal: Option<PathBuf>,
+    /// FIGlet (`.flf`) or BDF (`.bdf`) font file to draw the countdown and messages with.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    font: Option<PathBuf>,
+    /// Additional source to fill rain drops from, like `file:names.txt`, `twitch:<username>`,
+    /// `stdin` or `list:alice,bob`. Can be given multiple times and is merged with the other
+    /// sources, but isn't saved to the config file.
+    #[arg(short, long = "source", value_name = "SOURCE")]
+    sources: Vec<Source>,
     #[command(subcommand)]
     source: Option<Source>,
 }
 
-#[derive(Subcommand)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, Subcommand)]
+#[serde(tag = "type", rename_all = "lowercase")]
 enum Source {
     /// Fill rain drops with the content of a text file. Each line of the text file is considered
     /// one value.
@@ -63,18 +114,265 @@ enum Source {
         /// Streamer name to load viewer names from.
         username: String,
     },
+    /// Fill rain drops with values picked out of a JSON file.
+    Json {
+        /// Location to the JSON file.
+        #[arg(value_hint = ValueHint::FilePath)]
+        path: PathBuf,
+        /// JSONPath-like selector of the names, like `$.users[*].login`.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        select: Option<String>,
+    },
+    /// Fill rain drops with a column of a CSV file, that starts with a header row.
+    Csv {
+        /// Location to the CSV file.
+        #[arg(value_hint = ValueHint::FilePath)]
+        path: PathBuf,
+        /// Name or zero-based position of the column containing the names [default: 0].
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        column: Option<String>,
+    },
+    /// Fill rain drops with the contributors of a local Git repository.
+    Git {
+        /// Location of the repository.
+        #[arg(value_hint = ValueHint::DirPath)]
+        path: PathBuf,
+        /// Part of the commits to show.
+        #[arg(long, value_enum, default_value_t)]
+        #[serde(default)]
+        show: GitField,
+        /// Branch or tag to read the history of, instead of the current one.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        branch: Option<String>,
+        /// Only include commits after this date, like `2024-01-31` or `2 weeks ago`.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        since: Option<String>,
+        /// Only include commits before this date.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<String>,
+    },
+    /// Fill rain drops with the names of files in a directory and its sub-directories.
+    Dir {
+        /// Location of the directory.
+        #[arg(value_hint = ValueHint::DirPath)]
+        path: PathBuf,
+        /// Only include files matching the pattern, like `*.rs`. Can be given multiple times.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        glob: Vec<String>,
+        /// Levels of sub-directories to descend into, without limit if not set.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        depth: Option<usize>,
+        /// List the files again every given amount of seconds [default: 30].
+        #[arg(long, value_name = "SECONDS")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        interval: Option<u64>,
+    },
+    /// Fill rain drops with the names of running processes (Linux only).
+    Processes {
+        /// List the processes again every given amount of seconds [default: 5].
+        #[arg(long, value_name = "SECONDS")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        interval: Option<u64>,
+    },
+    /// Fill rain drops with new lines of a log file as it grows, following it across rotations.
+    /// The most recent lines are picked at random like other names, and new alert lines fall right
+    /// away.
+    Tail {
+        /// Location of the log file.
+        #[arg(value_hint = ValueHint::FilePath)]
+        path: PathBuf,
+        /// Regular expression to pick values out of each line, instead of using the whole line.
+        /// With capture groups, each group becomes a separate name.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        extract: Option<String>,
+        /// Show lines matching this regular expression, like `ERROR`, in the alert color. Can be
+        /// given multiple times.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        alert: Vec<String>,
+        /// Color of alert lines, as index into the 256 color palette [default: 196].
+        #[arg(long, value_name = "COLOR")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        alert_color: Option<u8>,
+        /// Amount of most recent names to keep in the rain [default: 100].
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keep: Option<usize>,
+    },
+    /// Fill rain drops with names downloaded from a URL, either as plain text, JSON or CSV.
+    #[cfg(feature = "http")]
+    Http {
+        /// Address to download the names from.
+        #[arg(value_hint = ValueHint::Url)]
+        url: String,
+        /// Format of the response. Guessed from the content type if not set.
+        #[arg(long, value_enum)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        format: Option<sources::Format>,
+        /// JSON selector or CSV column of the names.
+        #[arg(long)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        select: Option<String>,
+        /// Download the names again every given amount of seconds [default: 60].
+        #[arg(long, value_name = "SECONDS")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        interval: Option<u64>,
+    },
+    /// Fill rain drops with lines read from the standard input, until it's closed.
+    Stdin,
+    /// Fill rain drops with a fixed list of names.
+    List {
+        /// Names to use.
+        #[arg(required = true)]
+        names: Vec<String>,
+    },
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File { path } => write!(f, "file:{}", path.display()),
+            #[cfg(feature = "twitch")]
+            Self::Twitch { username } => write!(f, "twitch:{username}"),
+            Self::Json { path, select } => {
+                write!(f, "json:{}", path.display())?;
+                select.iter().try_for_each(|select| write!(f, "#{select}"))
+            }
+            Self::Csv { path, column } => {
+                write!(f, "csv:{}", path.display())?;
+                column.iter().try_for_each(|column| write!(f, "#{column}"))
+            }
+            Self::Git { path, branch, .. } => {
+                write!(f, "git:{}", path.display())?;
+                branch.iter().try_for_each(|branch| write!(f, "#{branch}"))
+            }
+            Self::Dir { path, .. } => write!(f, "dir:{}", path.display()),
+            Self::Processes { .. } => f.write_str("processes"),
+            Self::Tail { path, .. } => write!(f, "tail:{}", path.display()),
+            #[cfg(feature = "http")]
+            Self::Http { url, select, .. } => {
+                f.write_str(url)?;
+                select.iter().try_for_each(|select| write!(f, "#{select}"))
+            }
+            Self::Stdin => f.write_str("stdin"),
+            Self::List { names } => write!(f, "list:{}", names.join(",")),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Optional selector or column of structured sources, like `json:names.json#users[*]`.
+        let split = |value: &str| match value.split_once('#') {
+            Some((value, select)) => (value.to_owned(), Some(select.to_owned())),
+            None => (value.to_owned(), None),
+        };
+
+        match s.split_once(':') {
+            Some(("file", path)) if !path.is_empty() => Ok(Self::File { path: path.into() }),
+            Some(("json", path)) if !path.is_empty() => {
+                let (path, select) = split(path);
+                Ok(Self::Json {
+                    path: path.into(),
+                    select,
+                })
+            }
+            Some(("csv", path)) if !path.is_empty() => {
+                let (path, column) = split(path);
+                Ok(Self::Csv {
+                    path: path.into(),
+                    column,
+                })
+            }
+            Some(("git", path)) if !path.is_empty() => {
+                let (path, branch) = split(path);
+                Ok(Self::Git {
+                    path: path.into(),
+                    show: GitField::default(),
+                    branch,
+                    since: None,
+                    until: None,
+                })
+            }
+            Some(("dir", path)) if !path.is_empty() => Ok(Self::Dir {
+                path: path.into(),
+                glob: Vec::new(),
+                depth: None,
+                interval: None,
+            }),
+            Some(("tail", path)) if !path.is_empty() => Ok(Self::Tail {
+                path: path.into(),
+                extract: None,
+                alert: Vec::new(),
+                alert_color: None,
+                keep: None,
+            }),
+            #[cfg(feature = "http")]
+            Some(("http" | "https", _)) => {
+                let (url, select) = split(s);
+                Ok(Self::Http {
+                    url,
+                    format: None,
+                    select,
+                    interval: None,
+                })
+            }
+            #[cfg(feature = "twitch")]
+            Some(("twitch", username)) if !username.is_empty() => Ok(Self::Twitch {
+                username: username.to_owned(),
+            }),
+            Some(("list", names)) if !names.is_empty() => Ok(Self::List {
+                names: names.split(',').map(ToOwned::to_owned).collect(),
+            }),
+            None if s == "stdin" => Ok(Self::Stdin),
+            None if s == "processes" => Ok(Self::Processes { interval: None }),
+            _ => bail!(
+                "expected `file:<path>`, `json:<path>#<selector>`, `csv:<path>#<column>`, \
+                 `git:<path>#<branch>`, `dir:<path>`, `processes`, `tail:<path>`, a URL, \
+                 `twitch:<username>`, `stdin` or `list:<names>`"
+            ),
+        }
+    }
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 enum Showing {
     Nothing,
     Menu,
     Help,
     Time,
+    Stopwatch,
+    Clock,
+    Settings,
+    Message,
+    Spell,
+    Intro,
 }
 
 const SLEEP_TIME: Duration = Duration::from_millis(1000 / 25);
 
+/// How long the screen flashes after a countdown finished.
+const FLASH_TIME: Duration = Duration::from_secs(3);
+
+/// Length of the transition from the intro into the rain, without a playlist file.
+const INTRO_TRANSITION: Duration = Duration::from_millis(800);
+
+/// Watcher slot of the ticker's source, out of the way of the name sources that count up from 0.
+const TICKER_SLOT: usize = usize::MAX;
+
+/// Lengths of the countdowns that can be started from the menu, in minutes.
+const COUNTDOWN_MINUTES: [i64; 4] = [1, 5, 10, 15];
+
 const HELP_TEXT: &str = "\
 Welcome to rsmatrix a Matrix rain screensaver written in Rust.
 
@@ -84,56 +382,429 @@ The following commands can be used:
   - m toggle the menu to navigate to different areas
     - ▲ navigate menu up
     - ▼ navigate menu down
+    - ◀ or ⌫ go back to the previous menu
+  - space or ⏎ skip the intro
+  - space pause or resume the stopwatch
+  - l record a lap time of the stopwatch
+  - t toggle the ticker
+  - r hide the revealed image again
+  - n or p skip to the next or previous scene of the playlist
+  - ◀ ▶ change the selected value in the settings
   - q quit the application";
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
-    let namelist = if let Some(source) = args.source {
-        match source {
-            Source::File { path } => load_file(path)?,
-            #[cfg(feature = "twitch")]
-            Source::Twitch { username } => twitch::get_viewers(&username)?,
-        }
+    let config_path = args.config.or_else(Config::default_path);
+    let mut config = match &config_path {
+        Some(path) => Config::load(path)?,
+        None => Config::default(),
+    };
+
+    if let Some(fps) = args.fps {
+        config.fps = fps;
+    }
+    if let Some(dps) = args.dps {
+        config.dps = dps;
+    }
+    if args.source.is_some() {
+        config.source = args.source;
+    }
+    if args.font.is_some() {
+        config.font = args.font;
+    }
+    if args.intro {
+        config.intro.enabled = true;
+    }
+    if args.playlist.is_some() {
+        config.playlist = args.playlist;
+    }
+    if args.reveal.is_some() {
+        config.reveal.image = args.reveal;
+    }
+
+    // Additional sources from the command line only apply to this run, and aren't saved to the
+    // config file.
+    let sources = config
+        .sources
+        .iter()
+        .cloned()
+        .chain(args.sources.into_iter().map(SourceEntry::from))
+        .collect::<Vec<_>>();
+
+    let mut watcher = Watcher::default();
+    let mut main_names = start_source(&mut watcher, 0, config.source.as_ref())?;
+    let mut extra_names = sources
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            start_source(&mut watcher, i + 1, Some(&entry.source)).map(|names| entry.apply(names))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut namelist = merge_names(&config, &main_names, &extra_names)?;
+    let fallback = config.names.apply(
+        config
+            .names
+            .fallback
+            .iter()
+            .cloned()
+            .map(Name::new)
+            .collect(),
+    )?;
+    let font = config.font.as_deref().map(Font::load).transpose()?;
+    let font = font.as_ref().unwrap_or_else(|| Font::large());
+    let mut playlist = match &config.playlist {
+        Some(path) => Playlist::load(path)?,
+        None => Playlist::new(default_scenes(config.intro.enabled, args.countdown_until))
+            .with_transition(Transition::Dissolve, INTRO_TRANSITION),
+    };
+    let intro = if playlist.contains(|scene| matches!(scene, Scene::Intro { .. })) {
+        config.intro.load_lines()?
     } else {
-        vec!["test".to_owned()]
+        Vec::new()
     };
 
+    let picture = config
+        .reveal
+        .image
+        .as_deref()
+        .map(Picture::load)
+        .transpose()?;
+    let mut ticker = ticker_messages(
+        &config.ticker,
+        start_source(&mut watcher, TICKER_SLOT, config.ticker.source.as_ref())?,
+    );
+
     let mut terminal = create_terminal()?;
     let events = create_event_listener();
 
-    let update_speed = Duration::from_millis(1000 / args.fps);
-    let drop_speed = Duration::from_millis(1000 / args.dps);
     let mut background_state = KanaBackgroundState::default();
     let mut state = RainState::new();
     let mut border_state = KanaBorderState::default();
+    let mut error_border_state = KanaBorderState::default();
     let mut list_state = KanaListState::default();
+    let mut settings_state = KanaFormState::default();
+    let mut intro_state = TypewriterState::default();
+    let mut ticker_state = TickerState::default();
+    let mut reveal_state = RevealState::default();
+    let mut show_ticker = !ticker.is_empty();
+    let mut scene_changed = true;
     let mut showing = Showing::Nothing;
-    let mut timer_start = Instant::now();
-
-    let list_items = &["Countdown"];
+    let mut timer_end = Local::now();
+    let mut timer_finished = true;
+    // End of the countdown started by the current scene, which may be replaced by a countdown from
+    // the menu in the meantime.
+    let mut scene_timer_end = Local::now();
+    let mut flash_until = Instant::now();
+    let mut message = String::new();
+    let mut spell_state = SpellState::default();
+    let mut spell_hold = DEFAULT_SPELL_HOLD;
+    let mut spell_plain = false;
+    let mut stopwatch = Stopwatch::start();
+    let mut editing: Option<String> = None;
+    let mut status: Option<String> = None;
+    #[cfg(feature = "twitch")]
+    let channel_events = config
+        .events
+        .enabled
+        .then(|| events::listen(config.events.clone()));
+    #[cfg(feature = "twitch")]
+    let mut event_error: Option<String> = None;
 
     'drawloop: loop {
+        // Failed updates keep the last list of names, and only show up in the error overlay.
+        while let Some((slot, names)) = watcher.try_recv() {
+            if slot == TICKER_SLOT {
+                // A ticker that was empty so far shows up once there is something to scroll.
+                show_ticker |= ticker.is_empty();
+                ticker = ticker_messages(&config.ticker, names);
+                show_ticker &= !ticker.is_empty();
+                continue;
+            }
+
+            let (source, current, names) = match slot.checked_sub(1) {
+                None => (config.source.as_ref(), &mut main_names, names),
+                Some(i) => (
+                    Some(&sources[i].source),
+                    &mut extra_names[i],
+                    sources[i].apply(names),
+                ),
+            };
+
+            // New alert lines of a log file fall right away, instead of only joining the names
+            // that are picked at random.
+            if matches!(source, Some(Source::Tail { .. })) {
+                let alerts = sources::appended(current, &names)
+                    .iter()
+                    .filter(|name| name.alert)
+                    .cloned()
+                    .collect();
+                if let Ok(alerts) = config.names.apply(alerts) {
+                    alerts.into_iter().for_each(|name| state.push(name));
+                }
+            }
+            *current = names;
+
+            if let Ok(names) = merge_names(&config, &main_names, &extra_names) {
+                namelist = names;
+            }
+        }
+
+        #[cfg(feature = "twitch")]
+        while let Some(event) = channel_events
+            .as_ref()
+            .and_then(|events| events.try_recv().ok())
+        {
+            react(event, &mut state, &mut event_error, config.events.max_storm);
+        }
+
+        if scene_changed {
+            scene_changed = false;
+
+            if let Some(scene) = playlist.current() {
+                showing = match scene {
+                    Scene::Intro { .. } => {
+                        intro_state = TypewriterState::default();
+                        Showing::Intro
+                    }
+                    Scene::Rain { .. } => Showing::Nothing,
+                    Scene::Countdown { duration, until } => {
+                        timer_finished = false;
+                        timer_end = until
+                            .as_deref()
+                            .and_then(|until| countdown::parse_target(until).ok())
+                            .unwrap_or_else(|| {
+                                Local::now()
+                                    + TimeDelta::from_std(duration.unwrap_or_default())
+                                        .unwrap_or_default()
+                            });
+                        scene_timer_end = timer_end;
+                        Showing::Time
+                    }
+                    Scene::Message { text, .. } => {
+                        text.clone_into(&mut message);
+                        Showing::Message
+                    }
+                    Scene::Spell { text, hold, plain } => {
+                        text.clone_into(&mut message);
+                        spell_state = SpellState::default();
+                        spell_hold = hold.unwrap_or(DEFAULT_SPELL_HOLD);
+                        spell_plain = *plain;
+                        Showing::Spell
+                    }
+                    Scene::Clock { .. } => Showing::Clock,
+                    Scene::Stopwatch { .. } => {
+                        stopwatch = Stopwatch::start();
+                        Showing::Stopwatch
+                    }
+                };
+            }
+        }
+
+        let update_speed = Duration::from_millis(1000 / config.fps.max(1));
+        let drop_speed = Duration::from_millis(1000 / config.dps.max(1));
+        // Based on the wall clock, so the countdown stays correct if the system was suspended.
+        let remaining = (timer_end - Local::now()).to_std().unwrap_or_default();
+
+        if remaining.is_zero() && !timer_finished {
+            timer_finished = true;
+
+            for action in &config.countdown.on_finish {
+                match action {
+                    FinishAction::Flash => flash_until = Instant::now() + FLASH_TIME,
+                    FinishAction::Bell => {
+                        let mut stdout = io::stdout();
+                        stdout.write_all(b"\x07").and_then(|()| stdout.flush()).ok();
+                    }
+                    FinishAction::Message(text) => {
+                        if showing == Showing::Time {
+                            text.clone_into(&mut message);
+                            showing = Showing::Message;
+                        }
+                    }
+                    FinishAction::Spell(text) => {
+                        if showing == Showing::Time {
+                            text.clone_into(&mut message);
+                            spell_state = SpellState::default();
+                            spell_hold = DEFAULT_SPELL_HOLD;
+                            spell_plain = false;
+                            showing = Showing::Spell;
+                        }
+                    }
+                    FinishAction::Command(command) => run_command(command),
+                    FinishAction::Rain => {
+                        if matches!(showing, Showing::Time | Showing::Message | Showing::Spell) {
+                            showing = Showing::Nothing;
+                        }
+                    }
+                }
+            }
+        }
+
+        let countdown_items = [
+            ListItem::new("Resume").disabled(remaining.is_zero()),
+            ListItem::new("1 minute"),
+            ListItem::new("5 minutes"),
+            ListItem::new("10 minutes"),
+            ListItem::new("15 minutes"),
+        ];
+        let list_items = &[
+            ListItem::new("Countdown").submenu(&countdown_items),
+            ListItem::new("Stopwatch"),
+            ListItem::new("Clock"),
+            ListItem::new("Settings"),
+        ];
+
         terminal.draw(|f| {
             let size = f.size();
+            let theme = config.theme;
+            let palette = theme.palette();
+
+            if showing == Showing::Intro {
+                let intro = Typewriter::new(&intro)
+                    .char_delay(Duration::from_millis(config.intro.char_delay))
+                    .line_pause(Duration::from_millis(config.intro.line_pause))
+                    .theme(theme);
+
+                f.render_stateful_widget(intro, size, &mut intro_state);
+                f.render_widget(Dissolve::new(playlist.transition()).theme(theme), size);
+                return;
+            }
 
             f.render_stateful_widget(
-                KanaBackground::new(Duration::from_millis(300)),
+                KanaBackground::new(Duration::from_millis(300))
+                    .density(config.density)
+                    .theme(theme)
+                    .charset(config.charset),
                 size,
                 &mut background_state,
             );
+            if let Some(picture) = &picture {
+                f.render_stateful_widget(
+                    Reveal::new(picture, &state)
+                        .threshold(config.reveal.threshold)
+                        .theme(theme),
+                    size,
+                    &mut reveal_state,
+                );
+            }
+            let names = if namelist.is_empty() {
+                &fallback
+            } else {
+                &namelist
+            };
+
             f.render_stateful_widget(
-                Rain::new(&namelist, update_speed, drop_speed),
+                Rain::new(names, update_speed, drop_speed)
+                    .mode(config.names.mode)
+                    .no_repeat(config.names.no_repeat)
+                    .theme(theme)
+                    .charset(config.charset),
                 size,
                 &mut state,
             );
 
+            // Keep the large text clear of the ticker.
+            let ticker_height = if show_ticker {
+                Ticker::height().min(size.height)
+            } else {
+                0
+            };
+            let (content, ticker_area) = match config.ticker.position {
+                TickerPosition::Top => (
+                    Rect::new(
+                        size.x,
+                        size.y + ticker_height,
+                        size.width,
+                        size.height - ticker_height,
+                    ),
+                    Rect::new(size.x, size.y, size.width, ticker_height),
+                ),
+                TickerPosition::Bottom => (
+                    Rect::new(size.x, size.y, size.width, size.height - ticker_height),
+                    Rect::new(
+                        size.x,
+                        size.bottom() - ticker_height,
+                        size.width,
+                        ticker_height,
+                    ),
+                ),
+            };
+
+            if show_ticker {
+                f.render_stateful_widget(
+                    Ticker::new(&ticker).speed(config.ticker.speed).theme(theme),
+                    ticker_area,
+                    &mut ticker_state,
+                );
+            }
+
+            if config.names.show_progress && config.names.mode == PickMode::Shuffle {
+                let (shown, total) = state.progress(names);
+                let progress = format!(" {shown}/{total} ");
+                let width = (progress.len() as u16).min(content.width);
+                let r = Rect::new(
+                    content.right() - width,
+                    content.y,
+                    width,
+                    content.height.min(1),
+                );
+
+                f.render_widget(
+                    Paragraph::new(progress).style(Style::reset().fg(Color::Indexed(palette.tail))),
+                    r,
+                );
+            }
+
+            if (config.source.is_some() || !sources.is_empty()) && namelist.is_empty() {
+                let r = Rect::new(
+                    content.x,
+                    content.y,
+                    content.width.min(17),
+                    content.height.min(1),
+                );
+
+                f.render_widget(
+                    Paragraph::new(" NO NAMES LOADED ").style(
+                        Style::reset()
+                            .fg(Color::Indexed(palette.head))
+                            .bg(Color::Indexed(palette.shade))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    r,
+                );
+            }
+
+            #[cfg_attr(not(feature = "twitch"), allow(unused_mut))]
+            let mut errors = source_errors(&config, &sources, &watcher);
+            #[cfg(feature = "twitch")]
+            errors.extend(event_error.iter().map(|e| format!("twitch events: {e}")));
+            let width = content.width.min(72);
+            let lines = wrap_lines(&errors, width.saturating_sub(6).into());
+            let height = (lines.len() as u16 + 4).min(content.height);
+            if !errors.is_empty() && content.width >= 20 && height >= 5 {
+                let border = KanaBorder::default().title("ERROR").theme(theme);
+                let text = Paragraph::new(lines.join("\n"))
+                    .style(Style::default().fg(Color::Indexed(203)));
+
+                let r = Rect::new(content.x, content.bottom() - height, width, height);
+
+                f.render_widget(Clear, r);
+                f.render_stateful_widget(border, r, &mut error_border_state);
+
+                let r = r.inner(Margin {
+                    vertical: 2,
+                    horizontal: 3,
+                });
+                f.render_widget(text, r);
+            }
+
             match showing {
                 Showing::Menu => {
-                    let border = KanaBorder::default().title("MENU");
-                    let list = KanaList::new(list_items);
+                    let border = KanaBorder::default().title("MENU").theme(theme);
+                    let list = KanaList::new(list_items).theme(theme);
 
-                    let r = Rect::new(0, 0, 40, 4 + list_items.len() as u16);
+                    let r = Rect::new(0, 0, 40, 4 + list_state.current(list_items).len() as u16);
                     let r = r.center_in(size);
 
                     f.render_widget(Clear, r);
@@ -146,11 +817,12 @@ fn main() -> Result<()> {
                     f.render_stateful_widget(list, r, &mut list_state);
                 }
                 Showing::Help => {
-                    let border = KanaBorder::default().title("HELP");
-                    let help =
-                        Paragraph::new(HELP_TEXT).style(Style::default().fg(Color::Indexed(47)));
+                    let border = KanaBorder::default().title("HELP").theme(theme);
+                    let help = Paragraph::new(HELP_TEXT)
+                        .style(Style::default().fg(Color::Indexed(palette.head)));
 
-                    let r = Rect::new(0, 0, 68, 13).center_in(size);
+                    let r =
+                        Rect::new(0, 0, 68, HELP_TEXT.lines().count() as u16 + 4).center_in(size);
 
                     f.render_widget(Clear, r);
                     f.render_stateful_widget(border, r, &mut border_state);
@@ -163,25 +835,215 @@ fn main() -> Result<()> {
                     f.render_widget(help, r);
                 }
                 Showing::Time => {
-                    let duration = Duration::from_millis(300_500)
-                        .checked_sub(timer_start.elapsed())
-                        .unwrap_or_default();
+                    f.render_widget(
+                        Countdown::new(remaining)
+                            .font(font)
+                            .size(config.text_size)
+                            .theme(theme),
+                        content,
+                    );
+                }
+                Showing::Stopwatch => {
+                    let mut lines = stopwatch
+                        .laps()
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .take(5)
+                        .map(|(i, lap)| format!("LAP {:>2}  {}", i + 1, format_duration(*lap)))
+                        .collect::<Vec<_>>();
+                    if !stopwatch.is_running() {
+                        lines.insert(0, "PAUSED".to_owned());
+                    }
+
+                    // Keep room for the laps below the digits.
+                    let reserved = if lines.is_empty() {
+                        0
+                    } else {
+                        lines.len() as u16 + 2
+                    };
+                    let digits = Rect {
+                        height: content.height.saturating_sub(reserved),
+                        ..content
+                    };
+
+                    f.render_widget(
+                        Countdown::new(stopwatch.elapsed())
+                            .font(font)
+                            .size(config.text_size)
+                            .theme(theme),
+                        digits,
+                    );
 
-                    f.render_widget(Countdown { duration }, size);
+                    let r = Rect::new(0, 0, 20, lines.len() as u16).center_in(content);
+                    let r = Rect {
+                        y: digits.bottom() + 1,
+                        ..r
+                    }
+                    .intersection(content);
+                    let laps = Paragraph::new(lines.join("\n"))
+                        .style(Style::default().fg(Color::Indexed(palette.head)));
+
+                    f.render_widget(Clear, r);
+                    f.render_widget(laps, r);
                 }
-                Showing::Nothing => {}
+                Showing::Clock => {
+                    let now = Local::now();
+                    let time = Duration::from_secs(now.num_seconds_from_midnight().into());
+
+                    f.render_widget(
+                        Countdown::new(time)
+                            .hours(true)
+                            .font(font)
+                            .size(config.text_size)
+                            .theme(theme),
+                        content,
+                    );
+                }
+                Showing::Settings => {
+                    let border = KanaBorder::default().title("SETTINGS").theme(theme);
+                    let fields = Setting::ALL
+                        .iter()
+                        .map(|setting| {
+                            let value = match (setting, &editing) {
+                                (Setting::Source, Some(input)) => input.clone(),
+                                _ => setting.value(&config),
+                            };
+                            (setting.label(), value)
+                        })
+                        .collect::<Vec<_>>();
+                    let form = KanaForm::new(&fields)
+                        .editing(editing.is_some())
+                        .theme(theme);
+
+                    let r = Rect::new(0, 0, 60, 6 + fields.len() as u16).center_in(size);
+
+                    f.render_widget(Clear, r);
+                    f.render_stateful_widget(border, r, &mut border_state);
+
+                    let r = r.inner(Margin {
+                        vertical: 2,
+                        horizontal: 3,
+                    });
+                    f.render_stateful_widget(form, r, &mut settings_state);
+
+                    if let Some(status) = &status {
+                        let status = Paragraph::new(status.as_str())
+                            .style(Style::default().fg(Color::Indexed(palette.tail)));
+                        f.render_widget(status, Rect::new(r.x, r.bottom() - 1, r.width, 1));
+                    }
+                }
+                Showing::Message => {
+                    f.render_widget(
+                        BigText::new(&message)
+                            .font(font)
+                            .size(config.text_size)
+                            .theme(theme),
+                        content,
+                    );
+                }
+                Showing::Spell => {
+                    let spell = Spell::new(&message).hold(spell_hold).theme(theme);
+                    let spell = if spell_plain {
+                        spell
+                    } else {
+                        spell.font(font).size(config.text_size)
+                    };
+
+                    f.render_stateful_widget(spell, content, &mut spell_state);
+                }
+                Showing::Nothing | Showing::Intro => {}
+            }
+
+            f.render_widget(Dissolve::new(playlist.transition()).theme(theme), size);
+
+            // Alternate between inverted and regular colors while flashing.
+            let flashing = flash_until.saturating_duration_since(Instant::now());
+            if !flashing.is_zero() && flashing.as_millis() / 250 % 2 == 0 {
+                f.buffer_mut()
+                    .set_style(size, Style::default().add_modifier(Modifier::REVERSED));
             }
         })?;
 
+        let scene_done = playlist.is_over()
+            || match playlist.current() {
+                Some(Scene::Intro { .. }) => intro_state.is_finished(),
+                Some(Scene::Countdown { .. }) => Local::now() >= scene_timer_end,
+                Some(Scene::Spell { .. }) => spell_state.is_finished(),
+                _ => false,
+            };
+        if scene_done {
+            if playlist.next() {
+                scene_changed = true;
+            } else if showing == Showing::Intro {
+                showing = Showing::Nothing;
+            }
+        }
+        if showing == Showing::Spell && spell_state.is_finished() && !scene_changed {
+            showing = Showing::Nothing;
+        }
+
         thread::sleep(SLEEP_TIME);
 
-        while let Ok(event) = events.try_recv() {
+        while let Ok(key) = events.try_recv() {
+            if let Some(input) = &mut editing {
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        break 'drawloop
+                    }
+                    KeyCode::Enter => {
+                        let input = editing.take().unwrap_or_default();
+                        let source = edited_source(&input, config.source.as_ref());
+
+                        match source.and_then(|source| {
+                            // Reading the standard input would block the user interface, while the
+                            // terminal is in raw mode.
+                            if matches!(source, Some(Source::Stdin)) {
+                                bail!("stdin can only be used as source on the command line");
+                            }
+
+                            let names = match &source {
+                                Some(source) => watcher.try_start(0, sources::open(source)?)?,
+                                None => {
+                                    watcher.stop(0);
+                                    Vec::new()
+                                }
+                            };
+                            let merged = merge_names(&config, &names, &extra_names)?;
+                            Ok((source, names, merged))
+                        }) {
+                            Ok((source, names, merged)) => {
+                                config.source = source;
+                                main_names = names;
+                                namelist = merged;
+                                status = None;
+                            }
+                            Err(e) => status = Some(format!("{e:#}")),
+                        }
+                    }
+                    KeyCode::Esc => editing = None,
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Some(event) = map_key(key) else {
+                continue;
+            };
+
             match event {
                 KeyEvent::Quit => break 'drawloop,
                 KeyEvent::ToggleMenu => {
                     showing = match showing {
                         Showing::Menu => Showing::Nothing,
-                        _ => Showing::Menu,
+                        _ => {
+                            list_state.reset();
+                            Showing::Menu
+                        }
                     }
                 }
                 KeyEvent::ToggleHelp => {
@@ -190,27 +1052,94 @@ fn main() -> Result<()> {
                         _ => Showing::Help,
                     }
                 }
-                KeyEvent::MoveUp => {
-                    if showing == Showing::Menu {
-                        list_state.prev(list_items);
+                KeyEvent::MoveUp => match showing {
+                    Showing::Menu => list_state.prev(list_items),
+                    Showing::Settings => settings_state.prev(Setting::ALL.len()),
+                    _ => {}
+                },
+                KeyEvent::MoveDown => match showing {
+                    Showing::Menu => list_state.next(list_items),
+                    Showing::Settings => settings_state.next(Setting::ALL.len()),
+                    _ => {}
+                },
+                KeyEvent::MoveLeft => match showing {
+                    Showing::Menu => {
+                        list_state.back();
+                    }
+                    Showing::Settings => {
+                        Setting::ALL[settings_state.selected()].adjust(&mut config, -1);
+                    }
+                    _ => {}
+                },
+                KeyEvent::Back => {
+                    if showing == Showing::Menu && !list_state.back() {
+                        showing = Showing::Nothing;
+                    }
+                }
+                KeyEvent::MoveRight => {
+                    if showing == Showing::Settings {
+                        Setting::ALL[settings_state.selected()].adjust(&mut config, 1);
                     }
                 }
-                KeyEvent::MoveDown => {
-                    if showing == Showing::Menu {
-                        list_state.next(list_items);
+                KeyEvent::Pause => match showing {
+                    Showing::Stopwatch => stopwatch.toggle(),
+                    Showing::Intro => skip_intro(&mut playlist, &mut showing, &mut scene_changed),
+                    _ => {}
+                },
+                KeyEvent::RestartReveal => reveal_state.reset(),
+                KeyEvent::ToggleTicker => show_ticker = !show_ticker && !ticker.is_empty(),
+                KeyEvent::NextScene => scene_changed = playlist.next(),
+                KeyEvent::PrevScene => scene_changed = playlist.prev(),
+                KeyEvent::Lap => {
+                    if showing == Showing::Stopwatch {
+                        stopwatch.lap();
                     }
                 }
-                KeyEvent::Select => {
-                    if showing == Showing::Menu {
-                        showing = match list_state.selected() {
-                            0 => {
-                                timer_start = std::time::Instant::now();
+                KeyEvent::Select => match showing {
+                    Showing::Intro => skip_intro(&mut playlist, &mut showing, &mut scene_changed),
+                    Showing::Menu => {
+                        showing = match list_state.select(list_items).as_deref() {
+                            Some([0, 0]) => Showing::Time,
+                            Some(&[0, i]) => {
+                                timer_finished = false;
+                                timer_end = Local::now()
+                                    + COUNTDOWN_MINUTES
+                                        .get(i - 1)
+                                        .map_or(TimeDelta::zero(), |min| {
+                                            TimeDelta::milliseconds(min * 60_000 + 500)
+                                        });
                                 Showing::Time
                             }
-                            _ => Showing::Nothing,
+                            Some([1]) => {
+                                stopwatch = Stopwatch::start();
+                                Showing::Stopwatch
+                            }
+                            Some([2]) => Showing::Clock,
+                            Some([3]) => {
+                                status = None;
+                                Showing::Settings
+                            }
+                            Some(_) => Showing::Nothing,
+                            None => Showing::Menu,
                         }
                     }
-                }
+                    Showing::Settings => match Setting::ALL[settings_state.selected()] {
+                        Setting::Source => {
+                            editing = Some(
+                                config
+                                    .source
+                                    .as_ref()
+                                    .map(ToString::to_string)
+                                    .unwrap_or_default(),
+                            );
+                        }
+                        Setting::Save => {
+                            status = Some(save_config(&config, config_path.as_deref()));
+                        }
+                        _ => {}
+                    },
+                    _ => {}
+                },
             }
         }
     }
@@ -231,34 +1160,27 @@ enum KeyEvent {
     ToggleHelp,
     MoveUp,
     MoveDown,
+    MoveLeft,
+    MoveRight,
+    Back,
     Select,
+    Pause,
+    Lap,
+    ToggleTicker,
+    RestartReveal,
+    NextScene,
+    PrevScene,
 }
 
-fn create_event_listener() -> Receiver<KeyEvent> {
+/// Listen for raw key presses in the background. They're forwarded as is, as their meaning
+/// depends on the current state, like when entering text.
+fn create_event_listener() -> Receiver<event::KeyEvent> {
     let (tx, rx) = mpsc::channel();
 
     thread::spawn(move || {
         while let Ok(event) = event::read() {
-            let k = match event {
-                Event::Key(k) => k,
-                _ => continue,
-            };
-
-            let event = match k.code {
-                KeyCode::Esc | KeyCode::Char('q') => Some(KeyEvent::Quit),
-                KeyCode::Char('c') if k.modifiers.contains(KeyModifiers::CONTROL) => {
-                    Some(KeyEvent::Quit)
-                }
-                KeyCode::Char('m') => Some(KeyEvent::ToggleMenu),
-                KeyCode::Char('h') => Some(KeyEvent::ToggleHelp),
-                KeyCode::Up => Some(KeyEvent::MoveUp),
-                KeyCode::Down => Some(KeyEvent::MoveDown),
-                KeyCode::Enter => Some(KeyEvent::Select),
-                _ => None,
-            };
-
-            if let Some(event) = event {
-                tx.send(event).ok();
+            if let Event::Key(k) = event {
+                tx.send(k).ok();
             }
         }
     });
@@ -266,26 +1188,258 @@ fn create_event_listener() -> Receiver<KeyEvent> {
     rx
 }
 
+/// Translate a raw key press into an application command, if the key is bound to any.
+const fn map_key(k: event::KeyEvent) -> Option<KeyEvent> {
+    Some(match k.code {
+        KeyCode::Esc | KeyCode::Char('q') => KeyEvent::Quit,
+        KeyCode::Char('c') if k.modifiers.contains(KeyModifiers::CONTROL) => KeyEvent::Quit,
+        KeyCode::Char('m') => KeyEvent::ToggleMenu,
+        KeyCode::Char('h') => KeyEvent::ToggleHelp,
+        KeyCode::Up => KeyEvent::MoveUp,
+        KeyCode::Down => KeyEvent::MoveDown,
+        KeyCode::Left => KeyEvent::MoveLeft,
+        KeyCode::Right => KeyEvent::MoveRight,
+        KeyCode::Backspace => KeyEvent::Back,
+        KeyCode::Enter => KeyEvent::Select,
+        KeyCode::Char(' ') => KeyEvent::Pause,
+        KeyCode::Char('l') => KeyEvent::Lap,
+        KeyCode::Char('t') => KeyEvent::ToggleTicker,
+        KeyCode::Char('r') => KeyEvent::RestartReveal,
+        KeyCode::Char('n') | KeyCode::PageDown => KeyEvent::NextScene,
+        KeyCode::Char('p') | KeyCode::PageUp => KeyEvent::PrevScene,
+        _ => return None,
+    })
+}
+
 trait RectExt {
     fn center_in(self, outer: Self) -> Self;
 }
 
 impl RectExt for Rect {
     fn center_in(self, outer: Self) -> Self {
+        let width = self.width.min(outer.width);
+        let height = self.height.min(outer.height);
+
         Self::new(
-            (outer.width - self.width) / 2,
-            (outer.height - self.height) / 2,
-            self.width,
-            self.height,
+            outer.x + (outer.width - width) / 2,
+            outer.y + (outer.height - height) / 2,
+            width,
+            height,
         )
     }
 }
 
-fn load_file(path: PathBuf) -> Result<Vec<String>> {
-    BufReader::new(File::open(path)?)
-        .lines()
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(Into::into)
+/// Scenes to run without a playlist file, which is the intro if enabled, followed by either a
+/// countdown to the given target or the plain rain.
+fn default_scenes(intro: bool, countdown_until: Option<DateTime<Local>>) -> Vec<Scene> {
+    let mut scenes = Vec::with_capacity(2);
+    if intro {
+        scenes.push(Scene::Intro { duration: None });
+    }
+    scenes.push(
+        countdown_until.map_or(Scene::Rain { duration: None }, |until| Scene::Countdown {
+            duration: None,
+            until: Some(until.to_rfc3339()),
+        }),
+    );
+    scenes
+}
+
+/// Leave the intro early and move on to the next scene, or the plain rain if there is none.
+fn skip_intro(playlist: &mut Playlist, showing: &mut Showing, scene_changed: &mut bool) {
+    if playlist.next() {
+        *scene_changed = true;
+    } else {
+        *showing = Showing::Nothing;
+    }
+}
+
+/// Run a shell command in the background, without waiting for it to finish.
+fn run_command(command: &str) {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+
+    let child = cmd
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    if let Ok(mut child) = child {
+        thread::spawn(move || child.wait());
+    }
+}
+
+/// Format a duration as minutes, seconds and hundredths of a second, like `01:23.45`.
+fn format_duration(duration: Duration) -> String {
+    format!(
+        "{:02}:{:02}.{:02}",
+        duration.as_secs() / 60,
+        duration.as_secs() % 60,
+        duration.subsec_millis() / 10
+    )
+}
+
+/// Save the configuration to the given location and describe the outcome.
+fn save_config(config: &Config, path: Option<&Path>) -> String {
+    let Some(path) = path else {
+        return "no config file location available".to_owned();
+    };
+
+    match config.save(path) {
+        Ok(()) => format!("saved to {}", path.display()),
+        Err(e) => format!("{e:#}"),
+    }
+}
+
+/// Load the names of the given source, and keep watching it for updates in the given slot. Only
+/// invalid settings are an error, while sources that fail to load start out empty and are retried
+/// in the background.
+fn start_source(watcher: &mut Watcher, slot: usize, source: Option<&Source>) -> Result<Vec<Name>> {
+    source.map_or_else(
+        || Ok(Vec::new()),
+        |source| Ok(watcher.start(slot, sources::open(source)?)),
+    )
+}
+
+/// Let the rain react to an event of the Twitch channel. The user's name falls as a highlighted
+/// drop, and subscriptions, raids and cheers add a shower of colored drops on top.
+#[cfg(feature = "twitch")]
+fn react(event: ChannelEvent, state: &mut RainState, error: &mut Option<String>, max_storm: u16) {
+    const SUBSCRIBE_COLOR: u8 = 220;
+    const RAID_COLOR: u8 = 213;
+    const CHEER_COLOR: u8 = 135;
+    /// Drops for a single subscription.
+    const SUBSCRIBE_SHOWER: u32 = 8;
+    /// Bits that add another drop to a cheer.
+    const BITS_PER_DROP: u32 = 100;
+
+    let (name, shower) = match event {
+        ChannelEvent::Connected => {
+            *error = None;
+            return;
+        }
+        ChannelEvent::Error(e) => {
+            *error = Some(e);
+            return;
+        }
+        ChannelEvent::Follow(name) => (name, None),
+        ChannelEvent::Subscribe(name) => (name, Some((SUBSCRIBE_COLOR, SUBSCRIBE_SHOWER))),
+        // EventSub doesn't tell who joined the raid, and the chatters of the raiding channel can
+        // only be listed by its moderators. So every raiding viewer gets a drop with the raider's
+        // name instead.
+        ChannelEvent::Raid { from, viewers } => (from, Some((RAID_COLOR, viewers))),
+        ChannelEvent::Cheer { name, bits } => (name, Some((CHEER_COLOR, bits / BITS_PER_DROP))),
+    };
+
+    state.push(Name::new(name.clone()).highlighted());
+
+    if let Some((color, count)) = shower {
+        for _ in 0..count.min(max_storm.into()) {
+            state.push_storm(Name {
+                color: Some(color),
+                ..Name::new(name.clone())
+            });
+        }
+    }
+
+    // Keep at most a single storm waiting to fall, while the names of all events still fall.
+    state.limit_storm(max_storm.into());
+}
+
+/// Split the texts into lines that fit into the given amount of columns, each text starting on a
+/// new line. Lines are broken at spaces if possible, and words that are too long by themselves are
+/// split.
+fn wrap_lines(texts: &[String], width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for text in texts {
+        let mut line = String::new();
+
+        for word in text.split_whitespace() {
+            let space = usize::from(!line.is_empty());
+            if line.width() + space + word.width() <= width {
+                if space > 0 {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            for c in word.chars() {
+                if !line.is_empty() && line.width() + c.width().unwrap_or(0) > width {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(c);
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Parse the source typed into the settings, where nothing means no source. Confirming the current
+/// source unchanged keeps it as it is, as its text form leaves out most options.
+fn edited_source(input: &str, current: Option<&Source>) -> Result<Option<Source>> {
+    match input.trim() {
+        "" => Ok(None),
+        input if current.is_some_and(|current| current.to_string() == input) => {
+            Ok(current.cloned())
+        }
+        input => input.parse().map(Some),
+    }
+}
+
+/// Messages of the ticker, with the lines loaded from its source after the configured ones.
+fn ticker_messages(config: &TickerConfig, lines: Vec<Name>) -> Vec<String> {
+    config
+        .messages
+        .iter()
+        .cloned()
+        .chain(lines.into_iter().map(|name| name.text))
+        .collect()
+}
+
+/// Describe the errors of all failing sources, one per line.
+fn source_errors(config: &Config, extra: &[SourceEntry], watcher: &Watcher) -> Vec<String> {
+    watcher
+        .errors()
+        .map(|(slot, e)| {
+            let source = match slot {
+                TICKER_SLOT => config.ticker.source.as_ref(),
+                _ => slot.checked_sub(1).map_or(config.source.as_ref(), |i| {
+                    extra.get(i).map(|entry| &entry.source)
+                }),
+            };
+            source.map_or_else(|| e.to_owned(), |source| format!("{source}: {e}"))
+        })
+        .collect()
+}
+
+/// Merge the names of the main source with the ones of all additional sources and run them through
+/// the configured filters. Each source is filtered on its own, so only repeated names within the
+/// same source are removed, and every source keeps its own weights and colors.
+fn merge_names(config: &Config, main: &[Name], extra: &[Vec<Name>]) -> Result<Vec<Name>> {
+    let mut names = config.names.apply(main.to_vec())?;
+    for extra in extra {
+        names.extend(config.names.apply(extra.clone())?);
+    }
+
+    Ok(names)
 }
 
 struct RawMode<T>(T);
@@ -340,11 +1494,87 @@ impl<T: Write> Drop for AlternateScreen<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::Args;
+    use super::{edited_source, wrap_lines, Args, GitField, Source};
+    #[cfg(feature = "http")]
+    use crate::sources::Format;
 
     #[test]
     fn verify_cli() {
         use clap::CommandFactory;
         Args::command().debug_assert();
     }
+
+    #[test]
+    fn keep_source_options() {
+        let sources = [
+            Source::Tail {
+                path: "app.log".into(),
+                extract: Some(r"user=(\w+)".to_owned()),
+                alert: vec!["ERROR".to_owned()],
+                alert_color: Some(202),
+                keep: Some(10),
+            },
+            Source::Git {
+                path: ".".into(),
+                show: GitField::Subjects,
+                branch: Some("main".to_owned()),
+                since: Some("v1.0.0".to_owned()),
+                until: Some("yesterday".to_owned()),
+            },
+            Source::Dir {
+                path: "src".into(),
+                glob: vec!["*.rs".to_owned()],
+                depth: Some(1),
+                interval: Some(60),
+            },
+            Source::Processes { interval: Some(1) },
+            #[cfg(feature = "http")]
+            Source::Http {
+                url: "https://example.com/names.json".to_owned(),
+                format: Some(Format::Json),
+                select: Some("$.users[*]".to_owned()),
+                interval: Some(10),
+            },
+        ];
+
+        for source in sources {
+            let input = source.to_string();
+            assert_ne!(source, input.parse().unwrap());
+            assert_eq!(
+                Some(source.clone()),
+                edited_source(&input, Some(&source)).unwrap()
+            );
+        }
+
+        let current = Source::Stdin;
+        assert_eq!(None, edited_source(" ", Some(&current)).unwrap());
+        assert_eq!(
+            Some(Source::List {
+                names: vec!["neo".to_owned()]
+            }),
+            edited_source("list:neo", Some(&current)).unwrap()
+        );
+    }
+
+    #[test]
+    fn wrap_errors() {
+        let errors = [
+            "list: failed\nreading".to_owned(),
+            "url: 0123456789abcdef".to_owned(),
+            "日本語日本語日本".to_owned(),
+        ];
+
+        assert_eq!(
+            vec![
+                "list: failed",
+                "reading",
+                "url:",
+                "0123456789ab",
+                "cdef",
+                "日本語日本語",
+                "日本"
+            ],
+            wrap_lines(&errors, 12)
+        );
+    }
 }
@@ -146,3 +146,1120 @@ pub const SEMICOLON: [u8; 100] = [
     0,0,0,0,0,0,0,0,0,0,
     0,0,0,0,0,0,0,0,0,0,
 ];
+
+/// Uppercase letters from A to Z described as 10x10 arrays.
+#[rustfmt::skip]
+pub const LETTERS: &[[u8; 100]] = &[
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,1,1,1,1,1,1,
+        1,1,1,0,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,1,1,1,0,0,0,0,
+        1,1,1,1,1,1,0,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,0,0,1,1,1,1,
+        1,1,1,1,0,0,1,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,0,0,0,1,1,1,
+        1,1,1,1,0,0,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,0,0,1,1,1,1,
+        1,1,1,0,0,0,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,1,1,1,0,1,1,1,
+        1,1,1,1,1,1,0,1,1,1,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        0,0,0,1,1,1,1,1,1,1,
+        0,0,0,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,1,0,0,1,1,1,1,
+        1,1,1,1,0,0,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ],
+    [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ],
+    [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ],
+];
+
+/// Punctuation and common symbols described as 10x10 arrays, together with the character they
+/// represent.
+#[rustfmt::skip]
+pub const SYMBOLS: &[(char, [u8; 100])] = &[
+    (' ', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('.', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ]),
+    (',', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+    ]),
+    ('!', [
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ]),
+    ('?', [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,1,1,1,1,1,1,
+        0,0,0,0,1,1,1,1,1,1,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+    ]),
+    (';', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+    ]),
+    ('\'', [
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('"', [
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('-', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('+', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('=', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+    ]),
+    ('/', [
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+    ]),
+    ('(', [
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+    ]),
+    (')', [
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+    ]),
+    ('[', [
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+    ]),
+    (']', [
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+    ]),
+    ('<', [
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+    ]),
+    ('>', [
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+    ]),
+    ('#', [
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,1,0,0,1,0,0,0,
+        0,0,0,1,0,0,1,0,0,0,
+    ]),
+    ('%', [
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,0,0,1,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,0,1,1,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        0,0,0,1,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+    ]),
+    ('&', [
+        0,0,0,1,1,1,0,0,0,0,
+        0,0,0,1,1,1,0,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        0,0,0,1,1,1,0,1,1,1,
+        0,0,0,1,1,1,0,1,1,1,
+        1,1,1,0,0,0,1,0,0,0,
+        1,1,1,0,0,0,1,0,0,0,
+        0,0,0,1,1,1,0,1,1,1,
+        0,0,0,1,1,1,0,1,1,1,
+    ]),
+    ('*', [
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        1,1,1,0,1,1,0,1,1,1,
+        1,1,1,0,1,1,0,1,1,1,
+    ]),
+    ('_', [
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        0,0,0,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ]),
+    ('@', [
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,0,0,0,1,1,1,
+        1,1,1,0,1,1,1,1,1,1,
+        1,1,1,0,1,1,1,1,1,1,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,0,0,0,0,0,0,0,
+        1,1,1,1,1,1,1,1,1,1,
+        1,1,1,1,1,1,1,1,1,1,
+    ]),
+    ('$', [
+        0,0,0,1,1,1,1,1,1,1,
+        0,0,0,1,1,1,1,1,1,1,
+        1,1,1,0,1,1,0,0,0,0,
+        1,1,1,0,1,1,0,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,1,1,1,1,0,0,0,
+        0,0,0,0,1,1,0,1,1,1,
+        0,0,0,0,1,1,0,1,1,1,
+        1,1,1,1,1,1,1,0,0,0,
+        1,1,1,1,1,1,1,0,0,0,
+    ]),
+];
+
+/// Digits from 0 to 9 described as 5x5 arrays.
+#[rustfmt::skip]
+pub const DIGITS_SMALL: &[[u8; 25]] = &[
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,0,0,1,
+        1,1,1,1,1,
+        0,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,1,
+        0,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        0,0,0,0,1,
+        1,1,1,1,1,
+    ],
+];
+
+/// Uppercase letters from A to Z described as 5x5 arrays.
+#[rustfmt::skip]
+pub const LETTERS_SMALL: &[[u8; 25]] = &[
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+    ],
+    [
+        1,1,1,1,0,
+        1,0,0,0,1,
+        1,1,1,1,0,
+        1,0,0,0,1,
+        1,1,1,1,0,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,0,0,0,0,
+        1,0,0,0,0,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,0,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,0,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,0,
+        1,0,0,0,0,
+        1,1,1,1,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,0,
+        1,0,0,0,0,
+        1,0,0,0,0,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,0,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        1,1,1,1,1,
+    ],
+    [
+        0,0,0,0,1,
+        0,0,0,0,1,
+        0,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,1,0,
+        1,1,1,0,0,
+        1,0,0,1,0,
+        1,0,0,0,1,
+    ],
+    [
+        1,0,0,0,0,
+        1,0,0,0,0,
+        1,0,0,0,0,
+        1,0,0,0,0,
+        1,1,1,1,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,1,0,1,1,
+        1,0,1,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,1,0,0,1,
+        1,0,1,0,1,
+        1,0,0,1,1,
+        1,0,0,0,1,
+    ],
+    [
+        0,1,1,1,0,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        0,1,1,1,0,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,0,0,
+        1,0,0,0,0,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,0,1,0,1,
+        1,0,0,1,0,
+        1,1,1,0,1,
+    ],
+    [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+        1,0,0,1,0,
+        1,0,0,0,1,
+    ],
+    [
+        0,1,1,1,1,
+        1,0,0,0,0,
+        0,1,1,1,0,
+        0,0,0,0,1,
+        1,1,1,1,0,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,1,1,1,1,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,0,0,1,
+        0,1,0,1,0,
+        0,0,1,0,0,
+    ],
+    [
+        1,0,0,0,1,
+        1,0,0,0,1,
+        1,0,1,0,1,
+        1,1,0,1,1,
+        1,0,0,0,1,
+    ],
+    [
+        1,0,0,0,1,
+        0,1,0,1,0,
+        0,0,1,0,0,
+        0,1,0,1,0,
+        1,0,0,0,1,
+    ],
+    [
+        1,0,0,0,1,
+        0,1,0,1,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+    ],
+    [
+        1,1,1,1,1,
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+        1,1,1,1,1,
+    ],
+];
+
+/// Punctuation and common symbols described as 5x5 arrays, together with the character they
+/// represent.
+#[rustfmt::skip]
+pub const SYMBOLS_SMALL: &[(char, [u8; 25])] = &[
+    (' ', [
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+    ]),
+    ('.', [
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,1,0,0,
+    ]),
+    (',', [
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+    ]),
+    ('!', [
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+        0,0,1,0,0,
+    ]),
+    ('?', [
+        1,1,1,1,1,
+        0,0,0,0,1,
+        0,0,1,1,1,
+        0,0,0,0,0,
+        0,0,1,0,0,
+    ]),
+    (':', [
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+    ]),
+    (';', [
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+    ]),
+    ('\'', [
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+    ]),
+    ('"', [
+        0,1,0,1,0,
+        0,1,0,1,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+    ]),
+    ('-', [
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,1,1,1,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+    ]),
+    ('+', [
+        0,0,0,0,0,
+        0,0,1,0,0,
+        0,1,1,1,0,
+        0,0,1,0,0,
+        0,0,0,0,0,
+    ]),
+    ('=', [
+        0,0,0,0,0,
+        0,1,1,1,0,
+        0,0,0,0,0,
+        0,1,1,1,0,
+        0,0,0,0,0,
+    ]),
+    ('/', [
+        0,0,0,0,1,
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+        1,0,0,0,0,
+    ]),
+    ('(', [
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,0,1,0,
+    ]),
+    (')', [
+        0,1,0,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+    ]),
+    ('[', [
+        0,1,1,1,0,
+        0,1,0,0,0,
+        0,1,0,0,0,
+        0,1,0,0,0,
+        0,1,1,1,0,
+    ]),
+    (']', [
+        0,1,1,1,0,
+        0,0,0,1,0,
+        0,0,0,1,0,
+        0,0,0,1,0,
+        0,1,1,1,0,
+    ]),
+    ('<', [
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+        0,0,1,0,0,
+        0,0,0,1,0,
+    ]),
+    ('>', [
+        0,1,0,0,0,
+        0,0,1,0,0,
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+    ]),
+    ('#', [
+        0,1,0,1,0,
+        1,1,1,1,1,
+        0,1,0,1,0,
+        1,1,1,1,1,
+        0,1,0,1,0,
+    ]),
+    ('%', [
+        1,0,0,0,1,
+        0,0,0,1,0,
+        0,0,1,0,0,
+        0,1,0,0,0,
+        1,0,0,0,1,
+    ]),
+    ('&', [
+        0,1,1,0,0,
+        1,0,0,1,0,
+        0,1,1,0,1,
+        1,0,0,1,0,
+        0,1,1,0,1,
+    ]),
+    ('*', [
+        1,0,1,0,1,
+        0,1,1,1,0,
+        1,1,1,1,1,
+        0,1,1,1,0,
+        1,0,1,0,1,
+    ]),
+    ('_', [
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        0,0,0,0,0,
+        1,1,1,1,1,
+    ]),
+    ('@', [
+        1,1,1,1,1,
+        1,0,0,0,1,
+        1,0,1,1,1,
+        1,0,0,0,0,
+        1,1,1,1,1,
+    ]),
+    ('$', [
+        0,1,1,1,1,
+        1,0,1,0,0,
+        0,1,1,1,0,
+        0,0,1,0,1,
+        1,1,1,1,0,
+    ]),
+];
+
+/// Look up the 10x10 shape of a character. Lowercase letters share the shape of their uppercase
+/// counterpart.
+pub fn glyph(c: char) -> Option<&'static [u8]> {
+    let c = c.to_ascii_uppercase();
+    match c {
+        '0'..='9' => Some(&DIGITS[c as usize - '0' as usize]),
+        'A'..='Z' => Some(&LETTERS[c as usize - 'A' as usize]),
+        ':' => Some(&SEMICOLON),
+        _ => SYMBOLS
+            .iter()
+            .find(|(s, _)| *s == c)
+            .map(|(_, g)| g.as_slice()),
+    }
+}
+
+/// Look up the 5x5 shape of a character. Lowercase letters share the shape of their uppercase
+/// counterpart.
+pub fn glyph_small(c: char) -> Option<&'static [u8]> {
+    let c = c.to_ascii_uppercase();
+    match c {
+        '0'..='9' => Some(&DIGITS_SMALL[c as usize - '0' as usize]),
+        'A'..='Z' => Some(&LETTERS_SMALL[c as usize - 'A' as usize]),
+        _ => SYMBOLS_SMALL
+            .iter()
+            .find(|(s, _)| *s == c)
+            .map(|(_, g)| g.as_slice()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{DIGITS_SMALL, LETTERS_SMALL};
+
+    #[test]
+    fn distinct_small_glyphs() {
+        assert_eq!((10, 26), (DIGITS_SMALL.len(), LETTERS_SMALL.len()));
+        for glyphs in [DIGITS_SMALL, LETTERS_SMALL] {
+            assert_eq!(glyphs.len(), glyphs.iter().collect::<HashSet<_>>().len());
+        }
+    }
+}
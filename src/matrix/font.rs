@@ -0,0 +1,417 @@
+//! Bitmap fonts to draw ASCII-Art text with, either built from the shapes in [`asciiart`] or
+//! loaded from FIGlet (`.flf`) and BDF (`.bdf`) font files.
+//!
+//! [`asciiart`]: super::asciiart
+
+use std::{collections::HashMap, fs, path::Path, sync::OnceLock};
+
+use anyhow::{bail, ensure, Context, Result};
+
+use super::asciiart;
+
+/// Bitmap font with glyphs of a fixed height but possibly different widths.
+pub struct Font {
+    /// Height of all glyphs.
+    height: u16,
+    /// Shapes of all known characters.
+    glyphs: HashMap<char, Glyph>,
+}
+
+/// Shape of a single character in a [`Font`].
+pub struct Glyph {
+    /// Amount of columns the glyph takes up.
+    width: u16,
+    /// Whether a cell is set, row by row.
+    cells: Vec<bool>,
+}
+
+impl Glyph {
+    /// Create a glyph from a square shape, as found in the [`asciiart`] module.
+    fn from_square(shape: &[u8]) -> Self {
+        Self {
+            width: shape.len().isqrt() as u16,
+            cells: shape.iter().map(|&v| v != 0).collect(),
+        }
+    }
+
+    /// Amount of columns the glyph takes up.
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Iterate over the positions of all set cells, relative to the top left corner.
+    pub fn cells(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        let width = self.width.max(1) as usize;
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, set)| **set)
+            .map(move |(i, _)| ((i % width) as u16, (i / width) as u16))
+    }
+}
+
+impl Font {
+    /// The built-in font with 10x10 shapes.
+    pub fn large() -> &'static Self {
+        static FONT: OnceLock<Font> = OnceLock::new();
+        FONT.get_or_init(|| Self::builtin(10, asciiart::glyph))
+    }
+
+    /// The built-in font with 5x5 shapes.
+    pub fn small() -> &'static Self {
+        static FONT: OnceLock<Font> = OnceLock::new();
+        FONT.get_or_init(|| Self::builtin(5, asciiart::glyph_small))
+    }
+
+    /// Collect all printable ASCII characters that have a shape in the [`asciiart`] module.
+    fn builtin(height: u16, glyph: fn(char) -> Option<&'static [u8]>) -> Self {
+        Self {
+            height,
+            glyphs: (' '..='~')
+                .filter_map(|c| glyph(c).map(|shape| (c, Glyph::from_square(shape))))
+                .collect(),
+        }
+    }
+
+    /// Load a font from a file, where the format is determined by its extension.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed reading font from {}", path.display()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("flf") => Self::from_figlet(&content),
+            Some("bdf") => Self::from_bdf(&content),
+            _ => bail!("unknown font format, expected a `.flf` or `.bdf` file"),
+        }
+        .with_context(|| format!("failed parsing font from {}", path.display()))
+    }
+
+    /// Parse a FIGlet font. Every character of a glyph that isn't a space or hard blank is
+    /// considered a set cell.
+    pub fn from_figlet(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        let header = lines.next().unwrap_or_default();
+        let Some(params) = header.strip_prefix("flf2a") else {
+            bail!("missing FIGlet signature");
+        };
+
+        let mut params = params.chars();
+        let hardblank = params.next().context("missing hard blank")?;
+        let mut params = params.as_str().split_whitespace();
+        let mut param = |name| -> Result<i64> {
+            params
+                .next()
+                .with_context(|| format!("missing {name}"))?
+                .parse()
+                .with_context(|| format!("invalid {name}"))
+        };
+
+        let height = param("height")?;
+        param("baseline")?;
+        param("max length")?;
+        param("old layout")?;
+        let comments = param("comment lines")?;
+
+        ensure!(height > 0, "height must be positive");
+        ensure!(comments >= 0, "comment lines must not be negative");
+
+        let (height, comments) = (height as usize, comments as usize);
+
+        let mut lines = lines.skip(comments);
+        let mut glyphs = HashMap::new();
+        let required = (' '..='~').chain(['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß']);
+
+        for c in required {
+            let rows = (&mut lines).take(height).collect::<Vec<_>>();
+            ensure!(rows.len() == height, "missing glyph for {c:?}");
+            glyphs.insert(c, Self::figlet_glyph(&rows, hardblank));
+        }
+
+        // Any further glyphs are tagged with their character code.
+        while let Some(tag) = lines.next() {
+            let rows = (&mut lines).take(height).collect::<Vec<_>>();
+            let code = tag.split_whitespace().next().and_then(parse_figlet_code);
+
+            if let (Some(c), true) = (code.and_then(char::from_u32), rows.len() == height) {
+                glyphs.insert(c, Self::figlet_glyph(&rows, hardblank));
+            }
+        }
+
+        Ok(Self {
+            height: u16::try_from(height).context("font is too high")?,
+            glyphs,
+        })
+    }
+
+    /// Convert the lines of a single FIGlet character into a glyph. The end marks at the end of
+    /// each line are removed, which is any amount of repetitions of the last character.
+    fn figlet_glyph(rows: &[&str], hardblank: char) -> Glyph {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let row = row.trim_end();
+                let endmark = row.chars().last();
+                row.trim_end_matches(|c| Some(c) == endmark)
+                    .chars()
+                    .map(|c| c != ' ' && c != hardblank)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+
+        Glyph {
+            width: width as u16,
+            cells: rows
+                .into_iter()
+                .flat_map(|mut row| {
+                    row.resize(width, false);
+                    row
+                })
+                .collect(),
+        }
+    }
+
+    /// Parse a BDF font. Glyphs are aligned at the baseline and take up the full height of the
+    /// font's bounding box.
+    pub fn from_bdf(content: &str) -> Result<Self> {
+        let mut bbox = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut chars = Vec::new();
+        let mut current: Option<BdfChar> = None;
+        let mut in_bitmap = false;
+
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            let Some(keyword) = parts.next() else {
+                continue;
+            };
+            let values = parts
+                .filter_map(|v| v.parse::<i32>().ok())
+                .collect::<Vec<_>>();
+
+            if in_bitmap {
+                if keyword == "ENDCHAR" {
+                    in_bitmap = false;
+                    chars.extend(current.take());
+                } else if let Some(current) = &mut current {
+                    current.push_row(keyword)?;
+                }
+                continue;
+            }
+
+            match (keyword, values.as_slice()) {
+                ("FONTBOUNDINGBOX", &[w, h, x, y]) => bbox = Some((w, h, x, y)),
+                ("FONT_ASCENT", &[v]) => ascent = Some(v),
+                ("FONT_DESCENT", &[v]) => descent = Some(v),
+                ("STARTCHAR", _) => current = Some(BdfChar::default()),
+                ("ENCODING", &[code, ..]) => {
+                    if let Some(current) = &mut current {
+                        current.code = u32::try_from(code).ok().and_then(char::from_u32);
+                    }
+                }
+                ("DWIDTH", &[dx, ..]) => {
+                    if let Some(current) = &mut current {
+                        current.dwidth = Some(dx);
+                    }
+                }
+                ("BBX", &[w, h, x, y]) => {
+                    if let Some(current) = &mut current {
+                        current.bbx = (w, h, x, y);
+                    }
+                }
+                ("BITMAP", _) => in_bitmap = true,
+                _ => {}
+            }
+        }
+
+        let (_, bbox_h, _, bbox_y) = bbox.context("missing font bounding box")?;
+        let ascent = ascent.unwrap_or(bbox_h + bbox_y);
+        let height = ascent + descent.unwrap_or(-bbox_y);
+
+        ensure!(height > 0, "height must not be zero");
+
+        let glyphs = chars
+            .into_iter()
+            .filter_map(|c| Some((c.code?, c)))
+            .map(|(code, c)| Ok((code, c.glyph(ascent, height)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            height: u16::try_from(height).context("font is too high")?,
+            glyphs,
+        })
+    }
+
+    /// Height of all glyphs.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Look up the glyph of a character. Lowercase letters fall back to the uppercase glyph if the
+    /// font doesn't contain them.
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs
+            .get(&c)
+            .or_else(|| self.glyphs.get(&c.to_ascii_uppercase()))
+    }
+
+    /// Total width of the given characters, with a single column of space between each of them.
+    /// Unknown characters take up as much space as a blank.
+    pub fn text_width(&self, text: &[char]) -> u16 {
+        let blank = self.glyph(' ').map_or(self.height / 2, Glyph::width);
+        let width = text
+            .iter()
+            .map(|&c| self.glyph(c).map_or(blank, Glyph::width) + 1)
+            .sum::<u16>();
+
+        width.saturating_sub(1)
+    }
+}
+
+/// Character as described in a BDF font, before it's converted into a [`Glyph`].
+#[derive(Default)]
+struct BdfChar {
+    /// Character this glyph describes.
+    code: Option<char>,
+    /// Horizontal distance to the next glyph.
+    dwidth: Option<i32>,
+    /// Bounding box of the bitmap as width, height and offset from the origin.
+    bbx: (i32, i32, i32, i32),
+    /// Bitmap rows, where the most significant bits of each row are the leftmost pixels.
+    bitmap: Vec<u64>,
+}
+
+impl BdfChar {
+    /// Widest bitmap that is supported, as each row is kept in a single integer.
+    const MAX_WIDTH: i32 = u64::BITS as i32;
+
+    /// Add a row of the bitmap, given as hexadecimal digits that are padded to full bytes. The
+    /// row must match the width of the glyph's bounding box.
+    fn push_row(&mut self, row: &str) -> Result<()> {
+        let (w, ..) = self.bbx;
+        ensure!(
+            (0..=Self::MAX_WIDTH).contains(&w),
+            "glyph width of {w} is not within 0 to {} pixels",
+            Self::MAX_WIDTH
+        );
+        ensure!(
+            row.len() as i32 * 4 == (w + 7) / 8 * 8,
+            "bitmap row {row:?} doesn't match the glyph width of {w} pixels"
+        );
+
+        self.bitmap.push(
+            u64::from_str_radix(row, 16).with_context(|| format!("invalid bitmap row {row:?}"))?,
+        );
+        Ok(())
+    }
+
+    /// Place the bitmap within a glyph of the font's full height, aligned at the baseline.
+    fn glyph(&self, ascent: i32, height: i32) -> Result<Glyph> {
+        let (w, h, x, y) = self.bbx;
+        let width = self.dwidth.unwrap_or(w + x).max(w + x.max(0)).max(0);
+        let glyph_width = u16::try_from(width).context("glyph is too wide")?;
+        let top = ascent - (h + y);
+        let mut cells = vec![false; (width * height) as usize];
+
+        for (row, bits) in self.bitmap.iter().enumerate() {
+            // Rows are padded to full bytes.
+            let padded = (w + 7) / 8 * 8;
+
+            for col in 0..w {
+                let set = bits >> (padded - 1 - col) & 1 == 1;
+                let (cx, cy) = (x.max(0) + col, top + row as i32);
+
+                if set && (0..width).contains(&cx) && (0..height).contains(&cy) {
+                    cells[(cy * width + cx) as usize] = true;
+                }
+            }
+        }
+
+        Ok(Glyph {
+            width: glyph_width,
+            cells,
+        })
+    }
+}
+
+/// Parse the character code of a FIGlet code tag, which can be decimal, octal (with a leading
+/// `0`) or hexadecimal (with a leading `0x`).
+fn parse_figlet_code(value: &str) -> Option<u32> {
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"));
+
+    match hex {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None if value.len() > 1 && value.starts_with('0') => {
+            u32::from_str_radix(&value[1..], 8).ok()
+        }
+        None => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Font;
+
+    fn rows(font: &Font, c: char) -> Vec<String> {
+        let glyph = font.glyph(c).unwrap();
+        let mut rows = vec![vec!['.'; glyph.width() as usize]; font.height() as usize];
+        for (x, y) in glyph.cells() {
+            rows[y as usize][x as usize] = '#';
+        }
+        rows.into_iter().map(String::from_iter).collect()
+    }
+
+    #[test]
+    fn figlet() {
+        let mut content = "flf2a$ 2 2 4 -1 1\ncomment\n".to_owned();
+        for c in (' '..='~').chain(['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß']) {
+            if c == 'A' {
+                content.push_str("/\\@\n$|@@\n");
+            } else {
+                content.push_str("$@\n$@@\n");
+            }
+        }
+        content.push_str("0x263A smiley\n:)@\n$$@@\n");
+
+        let font = Font::from_figlet(&content).unwrap();
+
+        assert_eq!(2, font.height());
+        assert_eq!(vec!["##", ".#"], rows(&font, 'A'));
+        assert_eq!(vec!["##", ".."], rows(&font, '☺'));
+    }
+
+    #[test]
+    fn bdf() {
+        let content = "\
+STARTFONT 2.1
+FONTBOUNDINGBOX 4 4 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 3
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 1
+STARTCHAR period
+ENCODING 46
+DWIDTH 3 0
+BBX 2 2 1 0
+BITMAP
+C0
+40
+ENDCHAR
+ENDFONT
+";
+        let font = Font::from_bdf(content).unwrap();
+
+        assert_eq!(4, font.height());
+        assert_eq!(vec!["...", ".##", "..#", "..."], rows(&font, '.'));
+
+        let too_wide = content.replace("BBX 2 2 1 0", "BBX 72 2 1 0");
+        assert!(Font::from_bdf(&too_wide).is_err());
+        let short_rows = content.replace("BBX 2 2 1 0", "BBX 12 2 1 0");
+        assert!(Font::from_bdf(&short_rows).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::{
     collections::VecDeque,
+    iter,
+    ops::Range,
     time::{Duration, Instant},
 };
 
@@ -10,65 +12,160 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{StatefulWidget, Widget},
+    widgets::{Clear, StatefulWidget, Widget},
 };
+use unicode_width::UnicodeWidthChar;
 
-use crate::RectExt;
+use crate::{
+    names::{Name, PickMode, Picker},
+    RectExt,
+};
 
 mod asciiart;
+mod font;
+mod picture;
+mod theme;
+
+use self::font::Glyph;
+pub use self::{
+    font::Font,
+    picture::Picture,
+    theme::{Charset, Theme},
+};
+
+/// Maximum amount of queued drops that start falling within a single frame.
+const PENDING_PER_FRAME: usize = 4;
 
 /// The iconic Matrix rain widget drawing rain drops rendered as random characters. The tip of the
-/// rain drops contains random names for the namelist and tails are randomized characters.
+/// rain drops contains random names for the namelist and tails are randomized characters. If the
+/// namelist is empty, the drops consist of random characters only.
 #[derive(Copy, Clone)]
 pub struct Rain<'a> {
     /// List of names to pick from for new rain drops.
-    namelist: &'a [String],
+    namelist: &'a [Name],
     /// Speed at which rain drops move down in the scene or _fall_.
     update_speed: Duration,
     /// Speed at which new drops are added to the scene.
     drop_speed: Duration,
+    /// Strategy to pick names with.
+    mode: PickMode,
+    /// Avoid picking the same name twice in a row.
+    no_repeat: bool,
+    /// Colors of the drops.
+    theme: Theme,
+    /// Characters that the tails are made of.
+    charset: Charset,
 }
 
 impl<'a> Rain<'a> {
     /// Create a new Matrix rain with that picks random names from the given list to be drawn at the
     /// tip of rain drops. Update speed defines how fast rain drops fall and drop speed defines
     /// often new drops start falling from the top.
-    pub const fn new(namelist: &'a [String], update_speed: Duration, drop_speed: Duration) -> Self {
+    pub const fn new(namelist: &'a [Name], update_speed: Duration, drop_speed: Duration) -> Self {
         Self {
             namelist,
             update_speed,
             drop_speed,
+            mode: PickMode::Random,
+            no_repeat: false,
+            theme: Theme::Green,
+            charset: Charset::Matrix,
         }
     }
+
+    /// Set the strategy to pick names for new drops with.
+    pub const fn mode(self, mode: PickMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Avoid picking the same name for two drops in a row.
+    pub const fn no_repeat(self, no_repeat: bool) -> Self {
+        Self { no_repeat, ..self }
+    }
+
+    /// Set the colors of the drops.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+
+    /// Set the characters that the tails are made of.
+    pub const fn charset(self, charset: Charset) -> Self {
+        Self { charset, ..self }
+    }
 }
 
 /// State for the [`Rain`] widget.
-pub struct RainState<'a> {
+pub struct RainState {
     /// Pool of rain drops either active or not. Inactive drops can be reused as they left the
     /// drawing area already.
-    raindrops: Vec<RainDrop<'a>>,
+    raindrops: Vec<RainDrop>,
     /// Last time a new drop was added to the scene.
     last_drop: Instant,
     /// Last time all drops' position was updated.
     last_update: Instant,
+    /// Picks the names for new drops.
+    picker: Picker,
+    /// Names of drops that fall in addition to the regular ones, as soon as possible.
+    pending: VecDeque<Name>,
+    /// Drops of storms that fall once all pending names started falling.
+    storm: VecDeque<Name>,
 }
 
-impl<'a> RainState<'a> {
+impl RainState {
     /// Create a new empty rain state.
     pub fn new() -> Self {
         Self {
             raindrops: Vec::new(),
             last_drop: Instant::now(),
             last_update: Instant::now(),
+            picker: Picker::default(),
+            pending: VecDeque::new(),
+            storm: VecDeque::new(),
         }
     }
+
+    /// Let a drop with the given name fall in addition to the regular drops. Many queued drops are
+    /// spread over several frames, so they don't all start at once.
+    pub fn push(&mut self, name: Name) {
+        self.pending.push_back(name);
+    }
+
+    /// Let a drop of a storm fall, after all names pushed with [`Self::push`]. Unlike those, storm
+    /// drops can be limited.
+    #[cfg_attr(not(feature = "twitch"), allow(dead_code))]
+    pub fn push_storm(&mut self, name: Name) {
+        self.storm.push_back(name);
+    }
+
+    /// Drop the oldest queued storm drops beyond the given amount, so storms that come in faster
+    /// than they fall don't pile up. Pushed names are always kept.
+    #[cfg_attr(not(feature = "twitch"), allow(dead_code))]
+    pub fn limit_storm(&mut self, max: usize) {
+        let excess = self.storm.len().saturating_sub(max);
+        self.storm.drain(..excess);
+    }
+
+    /// Number of names that were shown in the current round of the shuffle mode, and the total
+    /// amount of names.
+    pub fn progress(&self, namelist: &[Name]) -> (usize, usize) {
+        self.picker.progress(namelist)
+    }
+
+    /// Positions of the tips of all drops that are currently falling.
+    pub fn tips(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.raindrops.iter().filter(|e| e.active).map(|e| e.pos)
+    }
 }
 
 /// A single Matrix rain drop as part of the [`RainState`].
 #[derive(Default)]
-struct RainDrop<'a> {
+struct RainDrop {
     /// Name to draw at the tip.
-    name: &'a str,
+    name: String,
+    /// Whether the name is drawn in a distinct style.
+    highlight: bool,
+    /// Color of the name instead of the default.
+    color: Option<u8>,
     /// Tail that's drawn directly behind the name.
     trail: VecDeque<char>,
     /// Current position within the terminal.
@@ -77,13 +174,49 @@ struct RainDrop<'a> {
     active: bool,
 }
 
-impl<'a> RainDrop<'a> {
-    /// Initialize a new rain drop with a random name from the given list, a tail of random
-    /// characters and a random horizontal position within the given area.
-    fn init(&mut self, rng: &mut impl Rng, area: Rect, namelist: &'a [String]) {
-        self.name = namelist.choose(rng).unwrap();
-        self.trail = (0..rng.sample(Uniform::new_inclusive(self.name.len(), self.name.len() * 2)))
-            .map(|_| random_char(rng))
+impl RainDrop {
+    /// Start a new drop, reusing an inactive one of the pool if possible.
+    fn spawn(
+        raindrops: &mut Vec<Self>,
+        rng: &mut impl Rng,
+        area: Rect,
+        name: Option<&Name>,
+        charset: Charset,
+    ) {
+        let index = raindrops.iter().position(|e| !e.active).unwrap_or_else(|| {
+            raindrops.push(Self::default());
+            raindrops.len() - 1
+        });
+        let element = &mut raindrops[index];
+
+        element.init(rng, area, name, charset);
+        element.active = true;
+    }
+
+    /// Initialize a new rain drop with the given name, a tail of random characters and a random
+    /// horizontal position within the given area. Without a name, the drop is made up of random
+    /// characters only.
+    fn init(&mut self, rng: &mut impl Rng, area: Rect, name: Option<&Name>, charset: Charset) {
+        match name {
+            Some(name) => {
+                name.text.clone_into(&mut self.name);
+                self.highlight = name.highlight;
+                self.color = name.color;
+            }
+            None => {
+                self.name.clear();
+                self.highlight = false;
+                self.color = None;
+            }
+        }
+
+        let len = if self.name.is_empty() {
+            6
+        } else {
+            self.name.len()
+        };
+        self.trail = (0..rng.sample(Uniform::new_inclusive(len, len * 2)))
+            .map(|_| charset.random(rng))
             .collect();
         self.pos = (rng.gen::<u16>() % area.right(), 0);
     }
@@ -100,33 +233,41 @@ impl<'a> RainDrop<'a> {
     }
 
     /// Draw the name vertically at the tip of the rain drop.
-    fn draw_name(&self, area: Rect, buf: &mut Buffer) {
+    fn draw_name(&self, area: Rect, buf: &mut Buffer, theme: Theme) {
+        let palette = theme.palette();
+        let style = if self.highlight {
+            Style::default()
+                .fg(Color::Indexed(231))
+                .bg(Color::Indexed(palette.highlight))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(Color::Indexed(self.color.unwrap_or(palette.head)))
+                .bg(Color::Indexed(palette.shade))
+                .add_modifier(Modifier::BOLD)
+        };
+
         for (i, c) in self.name.chars().rev().enumerate() {
             if let Some(pos) = self.pos.1.checked_sub(i as u16) {
                 if pos < area.bottom() {
-                    buf.get_mut(self.pos.0, pos)
-                        .set_style(
-                            Style::default()
-                                .fg(Color::Indexed(47))
-                                .bg(Color::Indexed(23))
-                                .add_modifier(Modifier::BOLD),
-                        )
-                        .set_char(c);
+                    buf.get_mut(self.pos.0, pos).set_style(style).set_char(c);
                 }
             }
         }
     }
 
     /// Draw the tail of the drop directly behind the name.
-    fn draw_tail(&self, area: Rect, buf: &mut Buffer) {
+    fn draw_tail(&self, area: Rect, buf: &mut Buffer, theme: Theme) {
+        let palette = theme.palette();
+
         for (i, c) in self.trail.iter().enumerate() {
             if let Some(pos) = self.pos.1.checked_sub((self.name.len() + i) as u16) {
                 if pos < area.bottom() {
                     buf.get_mut(self.pos.0, pos)
                         .set_fg(Color::Indexed(if i < self.trail.len() / 2 {
-                            35
+                            palette.tail
                         } else {
-                            23
+                            palette.shade
                         }))
                         .set_char(*c);
                 }
@@ -135,9 +276,9 @@ impl<'a> RainDrop<'a> {
     }
 
     /// Move the drop one step forward. This simply moves it one line down.
-    fn step(&mut self, rng: &mut impl Rng) {
+    fn step(&mut self, rng: &mut impl Rng, charset: Charset) {
         self.pos.1 += 1;
-        self.trail.push_front(random_char(rng));
+        self.trail.push_front(charset.random(rng));
         self.trail.pop_back();
     }
 }
@@ -166,27 +307,33 @@ fn random_katakana(rng: &mut impl Rng) -> char {
 }
 
 impl<'a> StatefulWidget for Rain<'a> {
-    type State = RainState<'a>;
+    type State = RainState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let rng = &mut rand::thread_rng();
 
+        state.picker.set_mode(self.mode);
+        state.picker.set_no_repeat(self.no_repeat);
+
         // Drop a new raindrop if needed.
         if state.last_drop.elapsed() > self.drop_speed {
-            let element = match state.raindrops.iter_mut().find(|e| !e.active) {
-                Some(element) => element,
-                None => {
-                    state.raindrops.push(RainDrop::default());
-                    state.raindrops.last_mut().unwrap()
-                }
-            };
-
-            element.init(rng, area, self.namelist);
-            element.active = true;
+            let name = state.picker.pick(self.namelist, rng);
+            RainDrop::spawn(&mut state.raindrops, rng, area, name, self.charset);
 
             state.last_drop = Instant::now();
         }
 
+        for _ in 0..PENDING_PER_FRAME {
+            let Some(name) = state
+                .pending
+                .pop_front()
+                .or_else(|| state.storm.pop_front())
+            else {
+                break;
+            };
+            RainDrop::spawn(&mut state.raindrops, rng, area, Some(&name), self.charset);
+        }
+
         let step = if state.last_update.elapsed() > self.update_speed {
             state.last_update = Instant::now();
             true
@@ -200,11 +347,11 @@ impl<'a> StatefulWidget for Rain<'a> {
                 continue;
             }
 
-            element.draw_name(area, buf);
-            element.draw_tail(area, buf);
+            element.draw_name(area, buf, self.theme);
+            element.draw_tail(area, buf, self.theme);
 
             if step {
-                element.step(rng);
+                element.step(rng, self.charset);
             }
         }
     }
@@ -226,6 +373,8 @@ impl<'a> StatefulWidget for Rain<'a> {
 pub struct KanaBorder<'a> {
     /// Optional title drawn at the top corner.
     title: Option<&'a str>,
+    /// Colors of the border and title.
+    theme: Theme,
 }
 
 /// State for the [`KanaBorder`] widget. This state can be shared by multiple border instances as it
@@ -239,7 +388,15 @@ pub struct KanaBorderState {
 impl<'a> KanaBorder<'a> {
     /// Set a title to be shown at the center top side border.
     pub const fn title(self, title: &'a str) -> Self {
-        Self { title: Some(title) }
+        Self {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    /// Set the colors of the border and title.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
     }
 
     /// Draw a title if it's set in the middle of the top border. A space is but before and after
@@ -258,7 +415,7 @@ impl<'a> KanaBorder<'a> {
                 pos.top(),
                 title,
                 Style::default()
-                    .fg(Color::Indexed(47))
+                    .fg(Color::Indexed(self.theme.palette().head))
                     .add_modifier(Modifier::BOLD),
             );
             buf.get_mut(pos.right(), pos.top()).reset();
@@ -271,6 +428,7 @@ impl<'a> StatefulWidget for KanaBorder<'a> {
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let rng = &mut rand::thread_rng();
+        let color = Color::Indexed(self.theme.palette().tail);
 
         (area.left()..area.right())
             .map(|x| (x, area.top()))
@@ -292,11 +450,9 @@ impl<'a> StatefulWidget for KanaBorder<'a> {
                     state.chars[i] = c;
                 }
 
-                buf.get_mut(x, y).set_char(c).set_style(
-                    Style::default()
-                        .fg(Color::Indexed(35))
-                        .add_modifier(Modifier::BOLD),
-                );
+                buf.get_mut(x, y)
+                    .set_char(c)
+                    .set_style(Style::default().fg(color).add_modifier(Modifier::BOLD));
             });
 
         self.draw_title(area, buf);
@@ -306,92 +462,380 @@ impl<'a> StatefulWidget for KanaBorder<'a> {
 /// List widget which can select a single item. The current item is indicated by a single Katakana
 /// character that changes randomly.
 ///
+/// Items can contain nested items, which are shown as a submenu when selected. If there are more
+/// items than fit into the area, the list scrolls along with the selection and arrows at the right
+/// side indicate that more items are available.
+///
 /// # Example output
 ///
 /// ```txt
-/// ｦ Countdown
+/// ｦ Countdown ›
+///   Settings
 /// ```
 pub struct KanaList<'a> {
-    items: &'a [&'a str],
+    items: &'a [ListItem<'a>],
+    /// Colors of the items and arrows.
+    theme: Theme,
 }
 
 impl<'a> KanaList<'a> {
-    /// Speed at which the pointer character is exchanged for a new random character.
-    const POINTER_REFRESH_TIME: Duration = Duration::from_millis(400);
-
     /// Create a new list widget with the given slice of items to display.
-    pub const fn new(items: &'a [&'a str]) -> Self {
-        Self { items }
+    pub const fn new(items: &'a [ListItem<'a>]) -> Self {
+        Self {
+            items,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Set the colors of the items and arrows.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
     }
 }
 
-/// State for the [`KanaList`] widget.
-pub struct KanaListState {
-    /// Index of the currently selected item.
-    selected: usize,
-    /// Random Katakana character to point at the current item.
-    pointer: char,
-    /// Last time the pointer has been updated.
+/// Single entry of the [`KanaList`].
+#[derive(Clone, Copy)]
+pub struct ListItem<'a> {
+    /// Text to display.
+    label: &'a str,
+    /// Disabled items are shown dimmed and can't be selected.
+    disabled: bool,
+    /// Nested items that are shown as a submenu when this item is selected.
+    children: &'a [Self],
+}
+
+impl<'a> ListItem<'a> {
+    /// Create a new item with the given label.
+    pub const fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            disabled: false,
+            children: &[],
+        }
+    }
+
+    /// Set whether the item is disabled and can't be selected.
+    pub const fn disabled(self, disabled: bool) -> Self {
+        Self { disabled, ..self }
+    }
+
+    /// Turn the item into a submenu that contains the given items.
+    pub const fn submenu(self, children: &'a [Self]) -> Self {
+        Self { children, ..self }
+    }
+
+    /// Whether the item contains nested items.
+    const fn is_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+/// Random Katakana character that points at the currently selected item of a list-like widget.
+struct Pointer {
+    /// Current character.
+    c: char,
+    /// Last time the character has been updated.
     last_update: Instant,
 }
 
-impl Default for KanaListState {
+impl Pointer {
+    /// Speed at which the pointer character is exchanged for a new random character.
+    const REFRESH_TIME: Duration = Duration::from_millis(400);
+
+    /// Get the current character, replacing it with a new random one if it has been shown long
+    /// enough.
+    fn get(&mut self) -> char {
+        if self.last_update.elapsed() > Self::REFRESH_TIME {
+            self.c = random_katakana(&mut rand::thread_rng());
+            self.last_update = Instant::now();
+        }
+
+        self.c
+    }
+}
+
+impl Default for Pointer {
     fn default() -> Self {
         Self {
-            selected: 0,
-            pointer: random_katakana(&mut rand::thread_rng()),
+            c: random_katakana(&mut rand::thread_rng()),
             last_update: Instant::now(),
         }
     }
 }
 
+/// State for the [`KanaList`] widget.
+#[derive(Default)]
+pub struct KanaListState {
+    /// Indices of the parent items of the currently shown submenu. Empty for the top level.
+    path: Vec<usize>,
+    /// Index of the currently selected item.
+    selected: usize,
+    /// Index of the first visible item, in case the list is scrolled.
+    offset: usize,
+    /// Random Katakana character to point at the current item.
+    pointer: Pointer,
+}
+
 impl KanaListState {
-    /// Currently selected list item.
-    pub const fn selected(&self) -> usize {
-        self.selected
+    /// Items of the currently shown (sub)menu.
+    pub fn current<'a>(&self, items: &'a [ListItem<'a>]) -> &'a [ListItem<'a>] {
+        self.path
+            .iter()
+            .try_fold(items, |items, &i| items.get(i).map(|item| item.children))
+            .unwrap_or_default()
+    }
+
+    /// Select the next enabled item in the list or wrap around to the first one if currently at
+    /// the bottom. Does nothing if there are no enabled items.
+    pub fn next(&mut self, items: &[ListItem<'_>]) {
+        let items = self.current(items);
+        let len = items.len();
+
+        if let Some(i) = (1..=len)
+            .map(|step| (self.selected + step) % len)
+            .find(|&i| !items[i].disabled)
+        {
+            self.selected = i;
+        }
     }
 
-    /// Select the next item in the list or jump to the first item if currently at the bottom.
-    pub fn next(&mut self, items: &[&str]) {
-        self.selected = (self.selected + 1) % items.len();
+    /// Select the previous enabled item in the list or wrap around to the last one if currently at
+    /// the top. Does nothing if there are no enabled items.
+    pub fn prev(&mut self, items: &[ListItem<'_>]) {
+        let items = self.current(items);
+        let len = items.len();
+
+        if let Some(i) = (1..=len)
+            .map(|step| (self.selected + len - step % len) % len)
+            .find(|&i| !items[i].disabled)
+        {
+            self.selected = i;
+        }
     }
 
-    /// Select the previous item in the list or jump to the last item if currently at the top.
-    pub fn prev(&mut self, items: &[&str]) {
-        if self.selected == 0 {
-            self.selected = items.len() - 1;
+    /// Activate the currently selected item. Submenus are entered, while for any other enabled item
+    /// the indices leading to it are returned, starting with the top level.
+    pub fn select(&mut self, items: &[ListItem<'_>]) -> Option<Vec<usize>> {
+        let item = self.current(items).get(self.selected)?;
+
+        if item.disabled {
+            None
+        } else if item.is_submenu() {
+            self.path.push(self.selected);
+            self.selected = 0;
+            self.offset = 0;
+            if self.current(items)[0].disabled {
+                self.next(items);
+            }
+            None
         } else {
-            self.selected -= 1;
+            let mut path = self.path.clone();
+            path.push(self.selected);
+            Some(path)
+        }
+    }
+
+    /// Leave the current submenu and go back to its parent. Returns `false` if already at the top
+    /// level.
+    pub fn back(&mut self) -> bool {
+        match self.path.pop() {
+            Some(parent) => {
+                self.selected = parent;
+                self.offset = 0;
+                true
+            }
+            None => false,
         }
     }
+
+    /// Go back to the top level of the list.
+    pub fn reset(&mut self) {
+        while self.back() {}
+    }
 }
 
 impl<'a> StatefulWidget for KanaList<'a> {
     type State = KanaListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        for (i, item) in self.items.iter().enumerate() {
-            let mut style = Style::default().fg(Color::Indexed(47));
+        let items = state.current(self.items);
+        let height = area.height as usize;
+        let pointer = state.pointer.get();
+        let palette = self.theme.palette();
+
+        if height == 0 || area.width < 3 {
+            return;
+        }
+
+        // Scroll just enough to keep the selected item visible.
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + height {
+            state.offset = state.selected + 1 - height;
+        }
+        state.offset = state.offset.min(items.len().saturating_sub(height));
+
+        for (i, item) in items.iter().enumerate().skip(state.offset).take(height) {
+            let y = area.top() + (i - state.offset) as u16;
+            let mut style = Style::default().fg(Color::Indexed(palette.head));
+
+            if item.disabled {
+                style = style
+                    .fg(Color::Indexed(palette.shade))
+                    .add_modifier(Modifier::DIM);
+            } else if i == state.selected {
+                style = style.add_modifier(Modifier::BOLD);
+                buf.get_mut(area.left(), y)
+                    .set_style(style)
+                    .set_char(pointer);
+            }
+
+            let (x, _) = buf.set_stringn(
+                area.left() + 2,
+                y,
+                item.label,
+                area.width as usize - 3,
+                style,
+            );
+
+            if item.is_submenu() && x + 1 < area.right() - 1 {
+                buf.get_mut(x + 1, y).set_style(style).set_char('›');
+            }
+        }
+
+        let arrow_style = Style::default().fg(Color::Indexed(palette.tail));
+        if state.offset > 0 {
+            buf.get_mut(area.right() - 1, area.top())
+                .set_style(arrow_style)
+                .set_char('▲');
+        }
+        if state.offset + height < items.len() {
+            buf.get_mut(area.right() - 1, area.bottom() - 1)
+                .set_style(arrow_style)
+                .set_char('▼');
+        }
+    }
+}
+
+/// State for the [`KanaForm`] widget.
+#[derive(Default)]
+pub struct KanaFormState {
+    /// Index of the currently selected field.
+    selected: usize,
+    /// Random Katakana character to point at the current field.
+    pointer: Pointer,
+}
+
+impl KanaFormState {
+    /// Currently selected field.
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select the next field or jump to the first one if currently at the bottom.
+    pub const fn next(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + 1) % len;
+        }
+    }
+
+    /// Select the previous field or jump to the last one if currently at the top.
+    pub const fn prev(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + len - 1) % len;
+        }
+    }
+}
+
+/// Form widget that shows a list of labeled values. Like the [`KanaList`], a single row can be
+/// selected and is indicated by a random Katakana character. The selected value can additionally be
+/// marked as being edited, which shows a cursor at its end.
+///
+/// # Example output
+///
+/// ```txt
+///   Frames/s  ◂ 5 ▸
+/// ﾈ Drops/s   ◂ 3 ▸
+///   Source    file:names.txt
+/// ```
+pub struct KanaForm<'a> {
+    /// Pairs of labels and their current value.
+    fields: &'a [(&'a str, String)],
+    /// Whether the value of the selected field is currently edited.
+    editing: bool,
+    /// Colors of the labels and values.
+    theme: Theme,
+}
+
+impl<'a> KanaForm<'a> {
+    /// Create a new form widget with the given label and value pairs to display.
+    pub const fn new(fields: &'a [(&'a str, String)]) -> Self {
+        Self {
+            fields,
+            editing: false,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Mark the value of the selected field as being edited.
+    pub const fn editing(self, editing: bool) -> Self {
+        Self { editing, ..self }
+    }
+
+    /// Set the colors of the labels and values.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+}
+
+impl<'a> StatefulWidget for KanaForm<'a> {
+    type State = KanaFormState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let pointer = state.pointer.get();
+
+        let label_width = self
+            .fields
+            .iter()
+            .map(|(label, _)| label.chars().count() as u16)
+            .max()
+            .unwrap_or_default();
+
+        for (i, (label, value)) in self.fields.iter().enumerate() {
+            let y = area.top() + i as u16;
+            let mut style = Style::default().fg(Color::Indexed(self.theme.palette().head));
 
-            if state.last_update.elapsed() > Self::POINTER_REFRESH_TIME {
-                state.pointer = random_katakana(&mut rand::thread_rng());
-                state.last_update = Instant::now();
+            if y >= area.bottom() {
+                break;
             }
 
             if i == state.selected {
                 style = style.add_modifier(Modifier::BOLD);
-                buf.get_mut(area.left(), area.top() + i as u16)
+                buf.get_mut(area.left(), y)
                     .set_style(style)
-                    .set_char(state.pointer);
+                    .set_char(pointer);
             }
 
-            buf.set_string(area.left() + 2, area.top() + i as u16, item, style);
+            buf.set_string(area.left() + 2, y, label, style);
+
+            let x = area.left() + 4 + label_width;
+            let (x, _) =
+                buf.set_stringn(x, y, value, area.right().saturating_sub(x) as usize, style);
+
+            if self.editing && i == state.selected && x < area.right() {
+                buf.get_mut(x, y)
+                    .set_style(style.add_modifier(Modifier::SLOW_BLINK))
+                    .set_char('_');
+            }
         }
     }
 }
 
-/// Countdown widget that draws the minutes and seconds of the duration field as ASCII-Art on the
-/// buffer. The content will be centered within the area.
+/// Countdown widget that draws the minutes and seconds of a duration as ASCII-Art on the buffer.
+/// Hours are drawn as well, if the duration is an hour or longer or if they're explicitly enabled.
+/// The content will be centered within the area.
+///
+/// Despite the name, it can draw any duration, like the time of a stopwatch or the time of the day.
 ///
 /// # Example output
 ///
@@ -407,122 +851,1257 @@ impl<'a> StatefulWidget for KanaList<'a> {
 /// 0991356113 0582759482                   847        245
 /// 1343533465 9348672928                   009        551
 /// ```
-pub struct Countdown {
-    /// Current duration to draw. Only minutes and seconds are considered.
-    pub duration: Duration,
-}
-
-impl Countdown {
-    /// Draw the shape of a single character described by the symbol array, where a non-zero value
-    /// means to draw a random digit and a zero value means not to draw anything at the position.
-    ///
-    /// The background color of each drawn cell has a chance to be a brighter color to generate a
-    /// flicker effect.
-    fn draw_shape(area: Rect, buf: &mut Buffer, rng: &mut impl Rng, symbol: [u8; 100]) {
-        for (y, row) in symbol.chunks_exact(10).enumerate() {
-            for (x, set) in row.iter().enumerate() {
-                if *set != 0 {
-                    let cell = buf.get_mut(area.x + x as u16, area.y + y as u16);
-                    cell.reset();
-                    cell.set_bg(Color::Indexed(if rng.next_u32() % 5 == 0 {
-                        35
-                    } else {
-                        23
-                    }))
-                    .set_fg(Color::Indexed(47))
-                    .set_char(random_digit(rng));
-                }
-            }
+pub struct Countdown<'a> {
+    /// Current duration to draw.
+    duration: Duration,
+    /// Whether to always draw the hours, even if they're zero.
+    hours: bool,
+    /// Font to draw the digits with.
+    font: &'a Font,
+    /// Percentage of the area's width or height the digits should fill.
+    size: u8,
+    /// Colors of the digits.
+    theme: Theme,
+}
+
+impl<'a> Countdown<'a> {
+    /// Create a new countdown widget that draws the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            hours: false,
+            font: Font::large(),
+            size: DEFAULT_TEXT_SIZE,
+            theme: Theme::Green,
         }
     }
+
+    /// Always draw the hours, even if the duration is shorter than an hour.
+    pub const fn hours(self, hours: bool) -> Self {
+        Self { hours, ..self }
+    }
+
+    /// Draw the digits with a different font than the built-in one.
+    pub const fn font(self, font: &'a Font) -> Self {
+        Self { font, ..self }
+    }
+
+    /// Set the percentage of the area's width or height that the digits should fill.
+    pub const fn size(self, size: u8) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Set the colors of the digits.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
 }
 
-impl Widget for Countdown {
+impl<'a> Widget for Countdown<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let rng = &mut rand::thread_rng();
-        let mut r = Rect::new(0, 0, 54, 10).center_in(area);
 
-        let secs = self.duration.as_secs() as usize;
+        let secs = self.duration.as_secs();
+        let text = if self.hours || secs >= 3600 {
+            format!(
+                "{:02}:{:02}:{:02}",
+                secs / 3600 % 100,
+                secs / 60 % 60,
+                secs % 60
+            )
+        } else {
+            format!("{:02}:{:02}", secs / 60, secs % 60)
+        };
+        let text = text.chars().collect::<Vec<_>>();
+
+        let (font, factor) = fit_text(area, self.size, self.font, |font| {
+            (font.text_width(&text), font.height())
+        });
+        let r = Rect::new(
+            0,
+            0,
+            scaled(font.text_width(&text), factor),
+            scaled(font.height(), factor),
+        )
+        .center_in(area);
 
-        Self::draw_shape(r, buf, rng, self::asciiart::DIGITS[secs / 60 / 10]);
-        r.x += 11;
-        Self::draw_shape(r, buf, rng, self::asciiart::DIGITS[secs / 60 % 10]);
-        r.x += 11;
-        Self::draw_shape(r, buf, rng, self::asciiart::SEMICOLON);
-        r.x += 11;
-        Self::draw_shape(r, buf, rng, self::asciiart::DIGITS[secs % 60 / 10]);
-        r.x += 11;
-        Self::draw_shape(r, buf, rng, self::asciiart::DIGITS[secs % 10]);
+        let scale = Scale::new(r.x, r.y, factor);
+        draw_text_line(buf, rng, font, &text, scale, self.theme, 0);
     }
 }
 
-pub struct KanaBackground {
-    update_speed: Duration,
+/// Default percentage of the area's width or height that ASCII-Art text fills.
+pub const DEFAULT_TEXT_SIZE: u8 = 80;
+
+/// Pick the font and scale factor to draw ASCII-Art text with, so it fills the given percentage of
+/// the area's width or height, whichever is reached first. The natural (unscaled) size of the text
+/// for a font is determined by `measure`.
+///
+/// The preferred font is scaled up if needed. If it's too large, the built-in small font is used
+/// instead, which is only scaled down if it doesn't fit either.
+fn fit_text(
+    area: Rect,
+    size: u8,
+    font: &Font,
+    measure: impl Fn(&Font) -> (u16, u16),
+) -> (&Font, f32) {
+    let size = f32::from(size.clamp(1, 100)) / 100.0;
+    let factor = |font: &Font| {
+        let (width, height) = measure(font);
+        if width == 0 || height == 0 {
+            return 1.0;
+        }
+        (f32::from(area.width) * size / f32::from(width))
+            .min(f32::from(area.height) * size / f32::from(height))
+    };
+
+    match factor(font) {
+        f if f >= 1.0 => (font, f),
+        f if std::ptr::eq(font, Font::small()) => (font, f),
+        _ => (Font::small(), factor(Font::small())),
+    }
 }
 
-pub struct KanaBackgroundState {
-    chars: Vec<(char, u16, u16)>,
-    last_update: Instant,
+/// Size of a length after scaling it by the factor, rounded up.
+fn scaled(len: u16, factor: f32) -> u16 {
+    (f32::from(len) * factor).ceil() as u16
 }
 
-impl Default for KanaBackgroundState {
-    fn default() -> Self {
-        Self {
-            chars: Vec::new(),
-            last_update: Instant::now(),
+/// Nearest-neighbor mapping of unscaled glyph positions to cells in the buffer.
+#[derive(Clone, Copy)]
+struct Scale {
+    /// Horizontal position in the buffer, where the unscaled content starts.
+    x: u16,
+    /// Vertical position in the buffer, where the unscaled content starts.
+    y: u16,
+    /// Factor to scale the content by.
+    factor: f32,
+}
+
+impl Scale {
+    const fn new(x: u16, y: u16, factor: f32) -> Self {
+        Self { x, y, factor }
+    }
+
+    /// Cells that a single unscaled cell covers along one axis. It's always at least one cell, so
+    /// thin strokes don't disappear when scaling down.
+    fn range(&self, origin: u16, pos: u16) -> Range<u16> {
+        let start = (f32::from(pos) * self.factor) as u16;
+        let end = ((f32::from(pos) + 1.0) * self.factor) as u16;
+        origin.saturating_add(start)..origin.saturating_add(end.max(start + 1))
+    }
+}
+
+/// Draw a single line of text with the given font, at the unscaled vertical offset `y`. Glyphs are
+/// separated by a single column and characters without a glyph are left blank.
+fn draw_text_line(
+    buf: &mut Buffer,
+    rng: &mut impl Rng,
+    font: &Font,
+    text: &[char],
+    scale: Scale,
+    theme: Theme,
+    y: u16,
+) {
+    let mut x = 0u16;
+
+    for &c in text {
+        match font.glyph(c) {
+            Some(glyph) => {
+                draw_glyph(buf, rng, glyph, scale, theme, (x, y));
+                x = x.saturating_add(glyph.width() + 1);
+            }
+            None => x = x.saturating_add(font.text_width(&[c]) + 1),
         }
     }
 }
 
-impl KanaBackground {
-    pub const fn new(update_speed: Duration) -> Self {
-        Self { update_speed }
+/// Draw the shape of a single glyph at the given unscaled position, where each set cell is filled
+/// with a random digit and other cells are left untouched.
+///
+/// The background color of each drawn cell has a chance to be a brighter color to generate a
+/// flicker effect.
+fn draw_glyph(
+    buf: &mut Buffer,
+    rng: &mut impl Rng,
+    glyph: &Glyph,
+    scale: Scale,
+    theme: Theme,
+    (x, y): (u16, u16),
+) {
+    let palette = theme.palette();
+
+    for (gx, gy) in glyph.cells() {
+        let xs = scale.range(scale.x, x.saturating_add(gx));
+        let ys = scale.range(scale.y, y.saturating_add(gy));
+
+        for (x, y) in ys.flat_map(|y| xs.clone().map(move |x| (x, y))) {
+            if buf.area.contains((x, y).into()) {
+                let cell = buf.get_mut(x, y);
+                cell.reset();
+                cell.set_bg(Color::Indexed(if rng.next_u32() % 5 == 0 {
+                    palette.tail
+                } else {
+                    palette.shade
+                }))
+                .set_fg(Color::Indexed(palette.head))
+                .set_char(random_digit(rng));
+            }
+        }
     }
+}
 
-    fn new_random(rng: &mut impl Rng, area: Rect) -> (char, u16, u16) {
-        (
-            random_katakana(&mut rand::thread_rng()),
-            rng.gen::<u16>() % area.right(),
-            rng.gen::<u16>() % area.bottom(),
-        )
+/// Big text widget that draws a message as ASCII-Art on the buffer, using the same flickering
+/// digit fill as the [`Countdown`]. Lines that don't fit into the area are wrapped at spaces and
+/// the content will be centered within the area. Characters without a known shape are left blank.
+///
+/// Like the [`Countdown`], the text is scaled to fill a percentage of the area, falling back to the
+/// built-in small font if the selected font is too large.
+///
+/// # Example output
+///
+/// ```txt
+/// 61538      55013 53432
+/// 9   1      3       2
+/// 77294      41975   0
+/// 0   8      2       7
+/// 35870      30285   9
+/// ```
+pub struct BigText<'a> {
+    /// Message to draw, possibly spanning multiple lines.
+    text: &'a str,
+    /// Font to draw the message with.
+    font: &'a Font,
+    /// Percentage of the area's width or height the text should fill.
+    size: u8,
+    /// Colors of the letters.
+    theme: Theme,
+}
+
+impl<'a> BigText<'a> {
+    /// Create a new big text widget that draws the given message.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            font: Font::large(),
+            size: DEFAULT_TEXT_SIZE,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Draw the message with a different font than the built-in one.
+    pub const fn font(self, font: &'a Font) -> Self {
+        Self { font, ..self }
+    }
+
+    /// Set the percentage of the area's width or height that the text should fill.
+    pub const fn size(self, size: u8) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Set the colors of the letters.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
     }
 }
 
-impl StatefulWidget for KanaBackground {
-    type State = KanaBackgroundState;
+/// Wrap the text for the font and calculate the total unscaled size it takes up, including a single
+/// row of space between lines.
+fn layout_text(text: &str, font: &Font, width: u16) -> (Vec<Vec<char>>, (u16, u16)) {
+    let lines = wrap_text(text, width, font);
+    let width = lines
+        .iter()
+        .map(|line| font.text_width(line))
+        .max()
+        .unwrap_or_default();
+    let height = (lines.len() as u16 * (font.height() + 1)).saturating_sub(1);
+    (lines, (width, height))
+}
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let rng = &mut rand::thread_rng();
-        let amount = area.width as usize * area.height as usize / 20;
+/// Split the text into lines that fit into the given amount of columns, when drawn with the font.
+/// Lines are broken at spaces if possible and words that are too long by themselves are split.
+fn wrap_text(text: &str, width: u16, font: &Font) -> Vec<Vec<char>> {
+    let mut lines = Vec::new();
 
-        if state.chars.len() != amount {
-            state.chars.clear();
-            state
-                .chars
-                .resize_with(amount, || Self::new_random(rng, area));
-        }
+    for paragraph in text.lines() {
+        let mut line = Vec::<char>::new();
 
-        if state.last_update.elapsed() > self.update_speed {
-            for _ in 0..amount / 20 {
-                if let Some(c) = state.chars.choose_mut(rng) {
-                    let new = Self::new_random(rng, area);
-                    *c = new;
+        for word in paragraph.split_whitespace() {
+            let mut candidate = line.clone();
+            if !candidate.is_empty() {
+                candidate.push(' ');
+            }
+            candidate.extend(word.chars());
+
+            if font.text_width(&candidate) <= width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            for c in word.chars() {
+                line.push(c);
+                if line.len() > 1 && font.text_width(&line) > width {
+                    line.pop();
+                    lines.push(std::mem::take(&mut line));
+                    line.push(c);
                 }
             }
-            state.last_update = Instant::now();
         }
 
-        for (c, x, y) in state
-            .chars
-            .iter()
-            .copied()
-            .filter(|v| area.contains((v.1, v.2).into()))
+        lines.push(line);
+    }
+
+    lines
+}
+
+impl<'a> Widget for BigText<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rng = &mut rand::thread_rng();
+        let wrap_width = (u32::from(area.width) * u32::from(self.size.clamp(1, 100)) / 100) as u16;
+
+        let (font, factor) = fit_text(area, self.size, self.font, |font| {
+            layout_text(self.text, font, wrap_width).1
+        });
+        let (lines, (_, height)) = layout_text(self.text, font, wrap_width);
+        let top = Rect::new(0, 0, area.width, scaled(height, factor))
+            .center_in(area)
+            .y;
+
+        for (i, line) in lines.iter().enumerate() {
+            let width = scaled(font.text_width(line), factor);
+            let x = Rect::new(0, 0, width, 1).center_in(area).x;
+            let y = i as u16 * (font.height() + 1);
+
+            let scale = Scale::new(x, top, factor);
+            draw_text_line(buf, rng, font, line, scale, self.theme, y);
+        }
+    }
+}
+
+/// Typewriter widget that types out lines of text one character at a time on an otherwise black
+/// screen, with a blinking cursor at the end. Each line replaces the previous one after it was
+/// shown for a while, like the opening scene of the movie.
+///
+/// # Example output
+///
+/// ```txt
+///   Wake up, Neo...█
+/// ```
+#[derive(Clone, Copy)]
+pub struct Typewriter<'a> {
+    /// Lines to type out, one after another.
+    lines: &'a [String],
+    /// Time it takes to type a single character.
+    char_delay: Duration,
+    /// Time a fully typed line stays on screen before the next one starts.
+    line_pause: Duration,
+    /// Colors of the text.
+    theme: Theme,
+}
+
+impl<'a> Typewriter<'a> {
+    /// Create a new typewriter widget for the given lines.
+    pub const fn new(lines: &'a [String]) -> Self {
+        Self {
+            lines,
+            char_delay: Duration::from_millis(120),
+            line_pause: Duration::from_millis(2500),
+            theme: Theme::Green,
+        }
+    }
+
+    /// Set the time it takes to type a single character.
+    pub const fn char_delay(self, char_delay: Duration) -> Self {
+        Self { char_delay, ..self }
+    }
+
+    /// Set the time a fully typed line stays on screen before the next one starts.
+    pub const fn line_pause(self, line_pause: Duration) -> Self {
+        Self { line_pause, ..self }
+    }
+
+    /// Set the colors of the text.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+}
+
+/// State for the [`Typewriter`] widget.
+pub struct TypewriterState {
+    /// Point in time the first line started typing.
+    started: Instant,
+    /// Whether all lines have been typed out and shown.
+    finished: bool,
+}
+
+impl Default for TypewriterState {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            finished: false,
+        }
+    }
+}
+
+impl TypewriterState {
+    /// Whether all lines have been typed out and shown.
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl<'a> StatefulWidget for Typewriter<'a> {
+    type State = TypewriterState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+        buf.set_style(area, Style::default().bg(Color::Black));
+
+        let mut elapsed = state.started.elapsed();
+
+        for line in self.lines {
+            let len = line.chars().count();
+            let typing = self.char_delay * len as u32;
+
+            if elapsed >= typing + self.line_pause {
+                elapsed -= typing + self.line_pause;
+                continue;
+            }
+
+            let typed = (elapsed.as_millis() / self.char_delay.as_millis().max(1)) as usize;
+            let typed = line.chars().take(typed).collect::<String>();
+            let style = Style::default()
+                .fg(Color::Indexed(self.theme.palette().head))
+                .bg(Color::Black);
+            let (x, y) = (area.left() + 2, area.top() + 1);
+
+            if area.width <= 3 || area.height <= 1 {
+                return;
+            }
+
+            let (x, _) = buf.set_stringn(x, y, &typed, (area.right() - x - 1) as usize, style);
+
+            // The cursor stays solid while typing and only blinks once the line is complete.
+            if elapsed < typing || (elapsed - typing).as_millis() / 500 % 2 == 0 {
+                buf.get_mut(x, y).set_style(style).set_char('█');
+            }
+
+            return;
+        }
+
+        state.finished = true;
+    }
+}
+
+/// Ticker that scrolls messages from right to left through a single line, framed by Katakana like
+/// the [`KanaBorder`]. The messages repeat endlessly, separated by a small diamond.
+///
+/// # Example output
+///
+/// ```txt
+/// ｿｴﾈｵﾒｯﾗﾉｱｦｷﾍｲﾄｶﾑﾎﾃﾁﾌｻｵｼﾛﾂｪﾀﾆﾎｹﾐｭﾑﾈﾀｦｫｺ
+/// ﾇ  our sponsors   ◆   next game: Tetris  ﾜ
+/// ﾏﾚｽﾈｾｸﾛｪﾚﾒﾋﾌﾃﾗｸﾓｳｾﾊｪｧｲｧﾄﾁﾒｮﾁﾆｬﾋｺｮﾅﾂﾘｲﾏﾗﾃ
+/// ```
+pub struct Ticker<'a> {
+    /// Messages to scroll through, one after another.
+    messages: &'a [String],
+    /// Scroll speed in cells per second.
+    speed: u16,
+    /// Colors of the text and frame.
+    theme: Theme,
+}
+
+/// State for the [`Ticker`] widget, keeping track of the scroll position.
+pub struct TickerState {
+    /// Point in time the ticker started scrolling, which the current position is based on.
+    started: Instant,
+    /// State of the frame around the ticker.
+    border: KanaBorderState,
+}
+
+impl Default for TickerState {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            border: KanaBorderState::default(),
+        }
+    }
+}
+
+impl<'a> Ticker<'a> {
+    /// Separator drawn between two messages.
+    const SEPARATOR: &'static str = "   ◆   ";
+
+    /// Create a new ticker for the given messages.
+    pub const fn new(messages: &'a [String]) -> Self {
+        Self {
+            messages,
+            speed: 10,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Set the scroll speed in cells per second.
+    pub const fn speed(self, speed: u16) -> Self {
+        Self { speed, ..self }
+    }
+
+    /// Set the colors of the text and frame.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+
+    /// Number of rows the ticker takes up, including its frame.
+    pub const fn height() -> u16 {
+        3
+    }
+}
+
+impl<'a> StatefulWidget for Ticker<'a> {
+    type State = TickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.height < Self::height() || area.width < 5 {
+            return;
+        }
+
+        let area = Rect {
+            height: Self::height(),
+            ..area
+        };
+        Clear.render(area, buf);
+        KanaBorder::default()
+            .theme(self.theme)
+            .render(area, buf, &mut state.border);
+
+        // Each cell of the text, where wide characters like CJK take up two cells and the second
+        // one is left empty.
+        let mut text = Vec::new();
+        for message in self.messages {
+            for c in message.chars().chain(Self::SEPARATOR.chars()) {
+                let width = c.width().unwrap_or_default();
+                if width > 0 {
+                    text.push(Some((c, width)));
+                    text.extend(iter::repeat_n(None, width - 1));
+                }
+            }
+        }
+        if text.is_empty() {
+            return;
+        }
+
+        let width = area.width as usize - 4;
+        let offset = (state.started.elapsed().as_secs_f32() * f32::from(self.speed)) as usize;
+        let style = Style::default()
+            .fg(Color::Indexed(self.theme.palette().head))
+            .add_modifier(Modifier::BOLD);
+        let (right, y) = (area.right() - 2, area.top() + 1);
+
+        // The text starts outside the right edge and then loops seamlessly.
+        for (x, i) in (area.left() + 2..right).zip(0..) {
+            let Some(pos) = (offset + i).checked_sub(width) else {
+                continue;
+            };
+
+            match text[pos % text.len()] {
+                Some((c, width)) if x as usize + width <= right as usize => {
+                    buf.set_stringn(x, y, c.encode_utf8(&mut [0; 4]), width, style);
+                }
+                // The rest of a wide character that is already drawn.
+                None if x > area.left() + 2 => {}
+                // Wide characters that are cut off at either edge.
+                _ => {
+                    buf.get_mut(x, y).set_char(' ').set_style(style);
+                }
+            }
+        }
+    }
+}
+
+/// Default time that a message spelled out by the rain is shown, once it's fully assembled.
+pub const DEFAULT_SPELL_HOLD: Duration = Duration::from_secs(5);
+
+/// Message that assembles out of the rain. Drops fall down and stop at the cells that make up the
+/// message, which then holds for a while, before the drops are released one by one and fall off the
+/// screen again.
+pub struct Spell<'a> {
+    /// Message to spell out.
+    text: &'a str,
+    /// Font to draw the message with, or plain text if not set.
+    font: Option<&'a Font>,
+    /// Percentage of the area's width or height the text should fill.
+    size: u8,
+    /// Time the fully assembled message is shown.
+    hold: Duration,
+    /// Colors of the message and the drops.
+    theme: Theme,
+}
+
+/// State for the [`Spell`] widget, holding the cells of the message and when they fall.
+pub struct SpellState {
+    /// Point in time the first drops started falling in.
+    started: Instant,
+    /// Area the targets were calculated for.
+    area: Rect,
+    /// Cells that make up the message, calculated on the first render.
+    targets: Vec<SpellTarget>,
+    /// Whether all drops fell off the screen again.
+    finished: bool,
+}
+
+/// Single cell of the message that a drop falls into.
+struct SpellTarget {
+    /// Position of the cell within the area.
+    pos: (u16, u16),
+    /// Character shown in the cell once the drop arrived.
+    c: char,
+    /// Style the character was drawn with, as plain text or large letters.
+    style: Style,
+    /// Time after the start at which the drop starts falling in.
+    enter: f32,
+    /// Additional time after holding the message at which the drop falls off again.
+    leave: f32,
+}
+
+impl Default for SpellState {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            area: Rect::default(),
+            targets: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl SpellState {
+    /// Whether all drops of the message fell off the screen again.
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl<'a> Spell<'a> {
+    /// Speed at which the drops fall, in cells per second.
+    const SPEED: f32 = 25.0;
+    /// Longest random delay before a drop starts falling, in seconds.
+    const SPREAD: f32 = 1.5;
+
+    /// Create a new widget that spells out the message as plain text.
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            font: None,
+            size: DEFAULT_TEXT_SIZE,
+            hold: DEFAULT_SPELL_HOLD,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Spell out the message in large letters with the given font.
+    pub const fn font(self, font: &'a Font) -> Self {
+        Self {
+            font: Some(font),
+            ..self
+        }
+    }
+
+    /// Set the percentage of the area's width or height that large letters should fill.
+    pub const fn size(self, size: u8) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Set the time the fully assembled message is shown.
+    pub const fn hold(self, hold: Duration) -> Self {
+        Self { hold, ..self }
+    }
+
+    /// Set the colors of the message and the drops.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+
+    /// Lay out the message and collect every cell that is part of it as a target for a drop.
+    fn targets(&self, area: Rect) -> Vec<SpellTarget> {
+        let rng = &mut rand::thread_rng();
+        let mut buf = Buffer::empty(area);
+
+        match self.font {
+            Some(font) => BigText::new(self.text)
+                .font(font)
+                .size(self.size)
+                .theme(self.theme)
+                .render(area, &mut buf),
+            None => {
+                let lines = self.text.lines().collect::<Vec<_>>();
+                let top = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+                let style = Style::default()
+                    .fg(Color::Indexed(self.theme.palette().head))
+                    .add_modifier(Modifier::BOLD);
+
+                for (y, line) in (top..area.bottom()).zip(lines) {
+                    let width = (line.chars().count() as u16).min(area.width);
+                    let x = area.x + (area.width - width) / 2;
+                    buf.set_stringn(x, y, line, width.into(), style);
+                }
+            }
+        }
+
+        let mut targets = Vec::new();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = buf.get(x, y);
+                let Some(c) = cell.symbol().chars().next().filter(|c| *c != ' ') else {
+                    continue;
+                };
+
+                targets.push(SpellTarget {
+                    pos: (x, y),
+                    c,
+                    style: cell.style(),
+                    enter: rng.gen_range(0.0..Self::SPREAD),
+                    leave: rng.gen_range(0.0..Self::SPREAD),
+                });
+            }
+        }
+
+        targets
+    }
+}
+
+impl<'a> StatefulWidget for Spell<'a> {
+    type State = SpellState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if state.area != area {
+            state.area = area;
+            state.targets = self.targets(area);
+        }
+
+        let elapsed = state.started.elapsed().as_secs_f32();
+        let assembled = state
+            .targets
+            .iter()
+            .map(|t| t.enter + f32::from(t.pos.1 - area.y) / Self::SPEED)
+            .fold(0.0, f32::max);
+        let released = assembled + self.hold.as_secs_f32();
+        let palette = self.theme.palette();
+        let head_style = Style::default()
+            .fg(Color::Indexed(palette.head))
+            .bg(Color::Indexed(palette.shade))
+            .add_modifier(Modifier::BOLD);
+
+        let rng = &mut rand::thread_rng();
+        let mut gone = 0;
+
+        for target in &state.targets {
+            let (x, y) = target.pos;
+            let time = elapsed - target.enter;
+            let release = released + target.leave;
+
+            // Row of the drop's tip, relative to the top of the area.
+            let head = if time < 0.0 {
+                continue;
+            } else if time * Self::SPEED < f32::from(y - area.y) {
+                time * Self::SPEED
+            } else if elapsed < release {
+                buf.get_mut(x, y).set_char(target.c).set_style(target.style);
+                continue;
+            } else {
+                (elapsed - release).mul_add(Self::SPEED, f32::from(y - area.y))
+            };
+
+            let head = head as u16;
+            if head >= area.height + 3 {
+                gone += 1;
+                continue;
+            }
+
+            let trail = [palette.head, palette.tail, palette.tail, palette.shade];
+            for (i, fg) in trail.into_iter().enumerate() {
+                let Some(row) = head.checked_sub(i as u16).filter(|row| *row < area.height) else {
+                    continue;
+                };
+
+                let cell = buf.get_mut(x, area.y + row);
+                if i == 0 {
+                    cell.set_char(target.c).set_style(head_style);
+                } else {
+                    cell.set_char(random_char(rng))
+                        .set_style(Style::reset().fg(Color::Indexed(fg)));
+                }
+            }
+        }
+
+        state.finished = gone == state.targets.len() && elapsed >= released;
+    }
+}
+
+/// Layer on top of the [`Rain`] that gradually reveals an image. Whenever the tip of a drop passes
+/// over a bright part of the image, it leaves a glyph behind that stays in place, so the image
+/// slowly appears out of the rain.
+pub struct Reveal<'a> {
+    picture: &'a Picture,
+    rain: &'a RainState,
+    /// Minimum brightness of a cell to be revealed.
+    threshold: u8,
+    /// Colors of the revealed glyphs.
+    theme: Theme,
+}
+
+/// State for the [`Reveal`] widget, holding the glyphs that were left behind so far.
+#[derive(Default)]
+pub struct RevealState {
+    /// Area the image was last sampled for.
+    area: Rect,
+    /// Brightness of each cell in the area.
+    cells: Vec<u8>,
+    /// Glyphs left behind in each cell.
+    revealed: Vec<Option<char>>,
+}
+
+impl RevealState {
+    /// Hide the image again, so it's revealed from scratch.
+    pub fn reset(&mut self) {
+        self.revealed.fill(None);
+    }
+}
+
+impl<'a> Reveal<'a> {
+    /// Create a new reveal layer for the given picture, using the drops of the given rain.
+    pub const fn new(picture: &'a Picture, rain: &'a RainState) -> Self {
+        Self {
+            picture,
+            rain,
+            threshold: 128,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Set the minimum brightness of a cell to be revealed.
+    pub const fn threshold(self, threshold: u8) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Set the colors of the revealed glyphs.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+}
+
+impl<'a> StatefulWidget for Reveal<'a> {
+    type State = RevealState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let rng = &mut rand::thread_rng();
+
+        if state.area != area {
+            state.area = area;
+            state.cells = self.picture.sample(area);
+            state.revealed = vec![None; state.cells.len()];
+        }
+
+        let index =
+            |x: u16, y: u16| (y - area.y) as usize * area.width as usize + (x - area.x) as usize;
+
+        for (x, y) in self.rain.tips() {
+            if area.contains((x, y).into()) {
+                let i = index(x, y);
+                if state.cells[i] >= self.threshold && state.revealed[i].is_none() {
+                    state.revealed[i] = Some(random_char(rng));
+                }
+            }
+        }
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let i = index(x, y);
+                let Some(c) = &mut state.revealed[i] else {
+                    continue;
+                };
+
+                if rng.next_u32() % 200 == 0 {
+                    *c = random_char(rng);
+                }
+
+                // Brighter parts of the image get lighter colors of the theme, leaving out the
+                // darkest one that the background uses.
+                let shades = &self.theme.palette().ramp()[1..];
+                let range = u16::from(u8::MAX - self.threshold) + 1;
+                let shade =
+                    u16::from(state.cells[i] - self.threshold) * shades.len() as u16 / range;
+
+                buf.get_mut(x, y).set_char(*c).set_style(
+                    Style::reset()
+                        .fg(Color::Indexed(shades[shade as usize]))
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
+        }
+    }
+}
+
+/// Transition effect that scrambles a portion of the screen into random characters. It's drawn on
+/// top of the next scene with a decreasing amount, so the scene gradually emerges from the noise.
+pub struct Dissolve {
+    /// Fraction of cells that are scrambled, from `0.0` to `1.0`.
+    amount: f32,
+    /// Colors of the scrambled cells.
+    theme: Theme,
+}
+
+impl Dissolve {
+    /// Create a new dissolve effect, scrambling the given fraction of cells.
+    pub const fn new(amount: f32) -> Self {
+        Self {
+            amount,
+            theme: Theme::Green,
+        }
+    }
+
+    /// Set the colors of the scrambled cells.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+}
+
+impl Widget for Dissolve {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rng = &mut rand::thread_rng();
+        let colors = self.theme.palette().ramp();
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if rng.gen::<f32>() < self.amount {
+                    let color = colors[rng.gen_range(0..colors.len())];
+                    buf.get_mut(x, y)
+                        .set_char(random_char(rng))
+                        .set_style(Style::reset().fg(Color::Indexed(color)));
+                }
+            }
+        }
+    }
+}
+
+pub struct KanaBackground {
+    update_speed: Duration,
+    /// Percentage of cells that are filled with a character.
+    density: u8,
+    /// Color of the characters.
+    theme: Theme,
+    /// Characters to fill the cells with.
+    charset: Charset,
+}
+
+pub struct KanaBackgroundState {
+    chars: Vec<(char, u16, u16)>,
+    last_update: Instant,
+}
+
+impl Default for KanaBackgroundState {
+    fn default() -> Self {
+        Self {
+            chars: Vec::new(),
+            last_update: Instant::now(),
+        }
+    }
+}
+
+impl KanaBackground {
+    pub const fn new(update_speed: Duration) -> Self {
+        Self {
+            update_speed,
+            density: 5,
+            theme: Theme::Green,
+            charset: Charset::Katakana,
+        }
+    }
+
+    /// Set the percentage of cells that are filled with a character.
+    pub const fn density(self, density: u8) -> Self {
+        Self { density, ..self }
+    }
+
+    /// Set the color of the characters.
+    pub const fn theme(self, theme: Theme) -> Self {
+        Self { theme, ..self }
+    }
+
+    /// Set the characters to fill the cells with.
+    pub const fn charset(self, charset: Charset) -> Self {
+        Self { charset, ..self }
+    }
+
+    fn new_random(&self, rng: &mut impl Rng, area: Rect) -> (char, u16, u16) {
+        // The background of the movie look consists of Katakana only.
+        let charset = match self.charset {
+            Charset::Matrix => Charset::Katakana,
+            charset => charset,
+        };
+
+        (
+            charset.random(&mut rand::thread_rng()),
+            rng.gen::<u16>() % area.right(),
+            rng.gen::<u16>() % area.bottom(),
+        )
+    }
+}
+
+impl StatefulWidget for KanaBackground {
+    type State = KanaBackgroundState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let rng = &mut rand::thread_rng();
+        let amount = area.width as usize * area.height as usize * self.density as usize / 100;
+
+        if state.chars.len() != amount {
+            state.chars.clear();
+            state
+                .chars
+                .resize_with(amount, || self.new_random(rng, area));
+        }
+
+        if state.last_update.elapsed() > self.update_speed {
+            for _ in 0..amount / 20 {
+                if let Some(c) = state.chars.choose_mut(rng) {
+                    let new = self.new_random(rng, area);
+                    *c = new;
+                }
+            }
+            state.last_update = Instant::now();
+        }
+
+        for (c, x, y) in state
+            .chars
+            .iter()
+            .copied()
+            .filter(|v| area.contains((v.1, v.2).into()))
         {
             buf.get_mut(x, y).set_char(c).set_style(
                 Style::reset()
-                    .fg(Color::Indexed(22))
+                    .fg(Color::Indexed(self.theme.palette().background))
                     .add_modifier(Modifier::DIM),
             );
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        time::{Duration, Instant},
+    };
+
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::{StatefulWidget, Widget},
+    };
+
+    use super::{
+        wrap_text, BigText, Countdown, Dissolve, Font, KanaListState, ListItem, Rain, RainState,
+        Spell, Theme, Ticker, TickerState, Typewriter, TypewriterState,
+    };
+    use crate::names::Name;
+
+    #[test]
+    fn list_navigation() {
+        let nested = [ListItem::new("a").disabled(true), ListItem::new("b")];
+        let items = [
+            ListItem::new("sub").submenu(&nested),
+            ListItem::new("off").disabled(true),
+            ListItem::new("leaf"),
+        ];
+        let mut state = KanaListState::default();
+
+        state.next(&items);
+        assert_eq!(Some(vec![2]), state.select(&items));
+        state.prev(&items);
+        assert_eq!(None, state.select(&items));
+        assert_eq!(Some(vec![0, 1]), state.select(&items));
+        assert!(state.back());
+        assert!(!state.back());
+    }
+
+    #[test]
+    fn empty_list() {
+        let mut state = KanaListState::default();
+
+        state.next(&[]);
+        state.prev(&[]);
+        assert_eq!(None, state.select(&[]));
+        assert!(!state.back());
+    }
+
+    #[test]
+    fn wrap() {
+        let font = Font::large();
+        let wrap = |text, width| {
+            wrap_text(text, width, font)
+                .into_iter()
+                .map(String::from_iter)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec!["BE", "RIGHT", "BACK", "WAKE", "UP"],
+            wrap("BE RIGHT BACK\nWAKE UP", 65)
+        );
+        assert_eq!(vec!["ABC", "DEF", "G"], wrap("ABCDEFG", 32));
+    }
+
+    #[test]
+    fn big_text_in_small_area() {
+        for (width, height) in [(0, 0), (3, 3), (20, 4), (80, 24)] {
+            let area = Rect::new(0, 0, width, height);
+            BigText::new("WAKE UP, NEO!").render(area, &mut Buffer::empty(area));
+        }
+    }
+
+    #[test]
+    fn countdown_scales() {
+        for (width, height) in [(200, 60), (80, 24), (40, 12), (20, 5)] {
+            let area = Rect::new(0, 0, width, height);
+            let mut buf = Buffer::empty(area);
+            Countdown::new(Duration::ZERO).render(area, &mut buf);
+
+            let drawn = area
+                .positions()
+                .filter(|&p| buf.get(p.x, p.y).symbol() != " ")
+                .collect::<Vec<_>>();
+            let cols = drawn.iter().map(|p| p.x).max().unwrap() + 1
+                - drawn.iter().map(|p| p.x).min().unwrap();
+            let rows = drawn.iter().map(|p| p.y).max().unwrap() + 1
+                - drawn.iter().map(|p| p.y).min().unwrap();
+
+            assert!(cols <= width * 8 / 10 && rows <= height * 8 / 10);
+            assert!(cols + 2 >= width * 8 / 10 || rows + 2 >= height * 8 / 10);
+        }
+    }
+
+    #[test]
+    fn typewriter_progress() {
+        let lines = ["WAKE UP".to_owned()];
+        let typewriter = Typewriter::new(&lines)
+            .char_delay(Duration::from_secs(1))
+            .line_pause(Duration::from_secs(5));
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        let typed = |buf: &Buffer| (2..7).map(|x| buf.get(x, 1).symbol()).collect::<String>();
+
+        let mut state = TypewriterState {
+            started: Instant::now() - Duration::from_millis(3500),
+            finished: false,
+        };
+        typewriter.render(area, &mut buf, &mut state);
+        assert_eq!("WAK█ ", typed(&buf));
+        assert!(!state.is_finished());
+
+        state.started = Instant::now() - Duration::from_secs(13);
+        typewriter.render(area, &mut buf, &mut state);
+        assert!(state.is_finished());
+    }
+
+    #[test]
+    fn ticker_wide_chars() {
+        let messages = ["日本 ok".to_owned()];
+        let area = Rect::new(0, 0, 20, 3);
+        let row = |scrolled: u64| {
+            let mut buf = Buffer::empty(area);
+            let mut state = TickerState {
+                started: Instant::now() - Duration::from_millis(scrolled * 1000 + 500),
+                ..TickerState::default()
+            };
+            Ticker::new(&messages)
+                .speed(1)
+                .render(area, &mut buf, &mut state);
+            (2..9).map(|x| buf.get(x, 1).symbol()).collect::<String>()
+        };
+
+        // Text starts scrolling in from the right edge, 16 cells away.
+        assert_eq!("日 本  ok", row(16));
+        assert_eq!(" 本  ok ", row(17));
+    }
+
+    #[test]
+    fn spell_targets() {
+        let targets = Spell::new("LIVE").targets(Rect::new(0, 0, 20, 5));
+        let cells = targets.iter().map(|t| (t.pos, t.c)).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![((8, 2), 'L'), ((9, 2), 'I'), ((10, 2), 'V'), ((11, 2), 'E')],
+            cells
+        );
+
+        let targets = Spell::new("LIVE")
+            .font(Font::large())
+            .targets(Rect::new(0, 0, 80, 24));
+        assert!(targets.len() > 40);
+    }
+
+    #[test]
+    fn rain_without_names() {
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+        let mut state = RainState::new();
+
+        for _ in 0..20 {
+            Rain::new(&[], Duration::ZERO, Duration::ZERO).render(area, &mut buf, &mut state);
+        }
+
+        assert!(state.tips().count() > 0);
+    }
+
+    #[test]
+    fn pushed_drops() {
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+        let mut state = RainState::new();
+        let rain = Rain::new(&[], Duration::from_secs(60), Duration::from_secs(60));
+
+        let texts = |queue: &VecDeque<Name>| {
+            queue
+                .iter()
+                .map(|name| name.text.clone())
+                .collect::<Vec<_>>()
+        };
+
+        state.push(Name::new("neo".to_owned()).highlighted());
+        for i in 0..8 {
+            state.push_storm(Name::new(format!("storm{i}")));
+        }
+        state.push(Name::new("trinity".to_owned()).highlighted());
+        state.limit_storm(3);
+
+        // Limiting drops the oldest storm drops, but never the pushed names.
+        assert_eq!(vec!["neo", "trinity"], texts(&state.pending));
+        assert_eq!(vec!["storm5", "storm6", "storm7"], texts(&state.storm));
+
+        rain.render(area, &mut buf, &mut state);
+        assert_eq!(4, state.tips().count());
+        assert!(state.pending.is_empty());
+        assert_eq!(vec!["storm7"], texts(&state.storm));
+        rain.render(area, &mut buf, &mut state);
+        assert_eq!(5, state.tips().count());
+    }
+
+    #[test]
+    fn themed_dissolve() {
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        Dissolve::new(1.0).theme(Theme::Mono).render(area, &mut buf);
+
+        let colors = Theme::Mono.palette().ramp();
+        assert!(buf
+            .content()
+            .iter()
+            .all(|cell| matches!(cell.fg, Color::Indexed(c) if colors.contains(&c))));
+    }
+}
@@ -0,0 +1,87 @@
+//! Loading of images that are revealed by the rain.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use image::{imageops::FilterType, GenericImageView};
+use ratatui::layout::Rect;
+
+/// Grayscale image, reduced to the brightness of each pixel.
+pub struct Picture {
+    image: image::GrayImage,
+}
+
+impl Picture {
+    /// Load a PNG or JPEG image from the given file. Transparent pixels are treated as black.
+    pub fn load(path: &Path) -> Result<Self> {
+        let image = image::open(path)
+            .with_context(|| format!("failed loading image from {}", path.display()))?;
+
+        Ok(Self::from_image(&image))
+    }
+
+    fn from_image(image: &impl GenericImageView<Pixel = image::Rgba<u8>>) -> Self {
+        let (width, height) = image.dimensions();
+        let image = image::GrayImage::from_fn(width, height, |x, y| {
+            let [r, g, b, a] = image.get_pixel(x, y).0;
+            let luma = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+            image::Luma([(luma * u32::from(a) / 255) as u8])
+        });
+
+        Self { image }
+    }
+
+    /// Downsample the image to the cells of the given area, keeping its aspect ratio and centering
+    /// it. Terminal cells are about twice as high as they're wide, so each cell covers two rows of
+    /// pixels for every column. The result contains the brightness of each cell, row by row.
+    pub fn sample(&self, area: Rect) -> Vec<u8> {
+        let mut cells = vec![0; area.area() as usize];
+        let (width, height) = self.image.dimensions();
+        if width == 0 || height == 0 || area.is_empty() {
+            return cells;
+        }
+
+        let scale = (f32::from(area.width) / width as f32)
+            .min(f32::from(area.height) * 2.0 / height as f32);
+        let columns = ((width as f32 * scale) as u16).clamp(1, area.width);
+        let rows = ((height as f32 * scale / 2.0) as u16).clamp(1, area.height);
+
+        let resized = image::imageops::resize(
+            &self.image,
+            columns.into(),
+            rows.into(),
+            FilterType::Triangle,
+        );
+        let left = (area.width - columns) / 2;
+        let top = (area.height - rows) / 2;
+
+        for (x, y, pixel) in resized.enumerate_pixels() {
+            let index =
+                (top as usize + y as usize) * area.width as usize + left as usize + x as usize;
+            cells[index] = pixel.0[0];
+        }
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgba, RgbaImage};
+    use ratatui::layout::Rect;
+
+    use super::Picture;
+
+    #[test]
+    fn sample_keeps_aspect_ratio() {
+        let image = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 255]));
+        let cells = Picture::from_image(&image).sample(Rect::new(0, 0, 20, 5));
+
+        // A square image becomes 10 columns wide and 5 rows high, centered horizontally.
+        let lit = |row: &[u8]| row.iter().map(|&c| c > 200).collect::<Vec<_>>();
+        for row in cells.chunks(20) {
+            let expected = (0..20).map(|x| (5..15).contains(&x)).collect::<Vec<_>>();
+            assert_eq!(expected, lit(row));
+        }
+    }
+}
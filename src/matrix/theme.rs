@@ -0,0 +1,145 @@
+//! Color themes and character sets that change the look of the rain.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{random_char, random_digit, random_katakana};
+
+/// Color scheme of the rain and the background.
+#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// The classic green of the movie.
+    #[default]
+    Green,
+    Blue,
+    Red,
+    Amber,
+    Purple,
+    /// Shades of gray.
+    Mono,
+}
+
+/// Colors of a theme, as indices into the 256 color palette.
+#[derive(Clone, Copy)]
+pub struct Palette {
+    /// Names at the tip of the drops.
+    pub head: u8,
+    /// Front half of the tails.
+    pub tail: u8,
+    /// Back half of the tails, and the background behind the names.
+    pub shade: u8,
+    /// Characters of the background.
+    pub background: u8,
+    /// Background behind highlighted names.
+    pub highlight: u8,
+}
+
+impl Theme {
+    /// All themes in the order they're cycled through in the settings.
+    pub const ALL: [Self; 6] = [
+        Self::Green,
+        Self::Blue,
+        Self::Red,
+        Self::Amber,
+        Self::Purple,
+        Self::Mono,
+    ];
+
+    /// Name of the theme, as used in the configuration file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Green => "green",
+            Self::Blue => "blue",
+            Self::Red => "red",
+            Self::Amber => "amber",
+            Self::Purple => "purple",
+            Self::Mono => "mono",
+        }
+    }
+
+    /// Colors that make up the theme.
+    pub const fn palette(self) -> Palette {
+        let (head, tail, shade, background, highlight) = match self {
+            Self::Green => (47, 35, 23, 22, 29),
+            Self::Blue => (45, 32, 24, 17, 25),
+            Self::Red => (203, 160, 52, 52, 88),
+            Self::Amber => (214, 172, 94, 58, 130),
+            Self::Purple => (177, 134, 54, 53, 91),
+            Self::Mono => (255, 248, 240, 236, 244),
+        };
+
+        Palette {
+            head,
+            tail,
+            shade,
+            background,
+            highlight,
+        }
+    }
+}
+
+impl Palette {
+    /// All colors of the palette, from the darkest to the brightest one.
+    pub const fn ramp(self) -> [u8; 5] {
+        [
+            self.background,
+            self.shade,
+            self.highlight,
+            self.tail,
+            self.head,
+        ]
+    }
+}
+
+/// Characters that the tails of the drops and the background are made of.
+#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Charset {
+    /// Mostly Half-Width Katakana, mixed with uppercase letters and digits, like in the movie.
+    #[default]
+    Matrix,
+    /// Half-Width Katakana only.
+    Katakana,
+    /// Uppercase letters and digits.
+    Latin,
+    /// Zeros and ones.
+    Binary,
+}
+
+impl Charset {
+    /// All character sets in the order they're cycled through in the settings.
+    pub const ALL: [Self; 4] = [Self::Matrix, Self::Katakana, Self::Latin, Self::Binary];
+
+    /// Name of the character set, as used in the configuration file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Matrix => "matrix",
+            Self::Katakana => "katakana",
+            Self::Latin => "latin",
+            Self::Binary => "binary",
+        }
+    }
+
+    /// Generate a random character of this set.
+    pub fn random(self, rng: &mut impl Rng) -> char {
+        match self {
+            Self::Matrix => random_char(rng),
+            Self::Katakana => random_katakana(rng),
+            Self::Latin => {
+                if rng.gen_ratio(10, 36) {
+                    random_digit(rng)
+                } else {
+                    rng.gen_range('A'..='Z')
+                }
+            }
+            Self::Binary => {
+                if rng.gen() {
+                    '1'
+                } else {
+                    '0'
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Names shown at the tip of rain drops, and how they're picked.
+
+use std::collections::HashSet;
+
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Single entry of the name list.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Name {
+    /// Text drawn at the tip of a drop.
+    pub text: String,
+    /// Relative chance of the name being picked, compared to the other names.
+    pub weight: u32,
+    /// Whether the name is drawn in a distinct style.
+    pub highlight: bool,
+    /// Color of the name, as index into the 256 color palette, instead of the default green.
+    pub color: Option<u8>,
+    /// Whether the name marks something that needs attention, so its color wins over the color of
+    /// its source.
+    pub alert: bool,
+}
+
+impl Name {
+    /// Create a regular name with the default weight.
+    pub const fn new(text: String) -> Self {
+        Self {
+            text,
+            weight: 1,
+            highlight: false,
+            color: None,
+            alert: false,
+        }
+    }
+
+    /// Mark the name as highlighted.
+    pub const fn highlighted(mut self) -> Self {
+        self.highlight = true;
+        self
+    }
+
+    /// Mark the name as alert with the given color.
+    pub const fn alert(mut self, color: u8) -> Self {
+        self.color = Some(color);
+        self.alert = true;
+        self
+    }
+}
+
+impl From<String> for Name {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Strategy to pick the next name with.
+#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PickMode {
+    /// Pick any name at random, so some names may repeat before others are shown at all.
+    #[default]
+    Random,
+    /// Show every name once before any of them repeats, like drawing them from a bag.
+    Shuffle,
+}
+
+/// Picks names from a list according to their weights.
+#[derive(Default)]
+pub struct Picker {
+    mode: PickMode,
+    /// Avoid picking the same name twice in a row.
+    no_repeat: bool,
+    /// Last picked name.
+    last: Option<String>,
+    /// Names that were already picked in the current round of the shuffle mode.
+    shown: HashSet<String>,
+}
+
+impl Picker {
+    /// Set the strategy to pick names with.
+    pub fn set_mode(&mut self, mode: PickMode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.shown.clear();
+        }
+    }
+
+    /// Set whether the same name may be picked twice in a row.
+    pub const fn set_no_repeat(&mut self, no_repeat: bool) {
+        self.no_repeat = no_repeat;
+    }
+
+    /// Number of names that were shown in the current round of the shuffle mode, and the total
+    /// amount of names.
+    pub fn progress(&self, names: &[Name]) -> (usize, usize) {
+        let shown = names
+            .iter()
+            .filter(|name| self.shown.contains(&name.text))
+            .count();
+        (shown, names.len())
+    }
+
+    /// Pick a random name from the list. Names with a higher weight are picked more often, and if
+    /// all weights are zero, every name has the same chance. In the shuffle mode, the weights only
+    /// decide which names come first in each round.
+    pub fn pick<'a>(&mut self, names: &'a [Name], rng: &mut impl Rng) -> Option<&'a Name> {
+        if self.mode == PickMode::Shuffle
+            && names.iter().all(|name| self.shown.contains(&name.text))
+        {
+            self.shown.clear();
+        }
+
+        let candidates = names
+            .iter()
+            .filter(|name| self.mode != PickMode::Shuffle || !self.shown.contains(&name.text))
+            .collect::<Vec<_>>();
+        let candidates = if self.no_repeat && candidates.len() > 1 {
+            candidates
+                .into_iter()
+                .filter(|name| self.last.as_ref() != Some(&name.text))
+                .collect()
+        } else {
+            candidates
+        };
+
+        let name = candidates
+            .choose_weighted(rng, |name| name.weight)
+            .ok()
+            .or_else(|| candidates.choose(rng))
+            .copied()?;
+
+        name.text
+            .clone_into(self.last.get_or_insert_with(String::new));
+        if self.mode == PickMode::Shuffle {
+            self.shown.insert(name.text.clone());
+        }
+        Some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Name, PickMode, Picker};
+
+    #[test]
+    fn weighted_without_repeats() {
+        let rng = &mut StdRng::seed_from_u64(7);
+        let names = [
+            Name {
+                weight: 10,
+                ..Name::new("often".to_owned())
+            },
+            Name::new("rare".to_owned()),
+            Name {
+                weight: 0,
+                ..Name::new("never".to_owned())
+            },
+        ];
+
+        let mut picker = Picker::default();
+        let often = (0..1000)
+            .filter(|_| picker.pick(&names, rng).unwrap().text == "often")
+            .count();
+        assert!(often > 800);
+
+        picker.set_no_repeat(true);
+        let mut last = String::new();
+        for _ in 0..100 {
+            let name = &picker.pick(&names, rng).unwrap().text;
+            assert_ne!(&last, name);
+            assert_ne!("never", name);
+            name.clone_into(&mut last);
+        }
+
+        assert!(picker.pick(&[], rng).is_none());
+    }
+
+    #[test]
+    fn shuffle_shows_everyone() {
+        let rng = &mut StdRng::seed_from_u64(7);
+        let names = ["a", "b", "c", "d", "e"].map(|name| Name::new(name.to_owned()));
+
+        let mut picker = Picker::default();
+        picker.set_mode(PickMode::Shuffle);
+
+        for _ in 0..3 {
+            let mut round = (0..names.len())
+                .map(|_| picker.pick(&names, rng).unwrap().text.clone())
+                .collect::<Vec<_>>();
+            assert_eq!((5, 5), picker.progress(&names));
+
+            round.sort();
+            assert_eq!(vec!["a", "b", "c", "d", "e"], round);
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Playlists that run a sequence of scenes one after another, so the screensaver can run a whole
+//! stream unattended.
+
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Deserializer};
+
+use crate::countdown::parse_target;
+
+/// Single step of a playlist, describing what is shown on top of the rain.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Scene {
+    /// Type out the intro lines. Without a duration it ends once all lines are typed.
+    Intro {
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+    },
+    /// Show only the rain.
+    Rain {
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+    },
+    /// Count down for a duration or to a point in time, and end when the countdown finishes.
+    Countdown {
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+        /// Time of the day or timestamp to count down to. It's resolved whenever the scene starts,
+        /// so a looping playlist counts down to the next occurrence of the time every round.
+        #[serde(default, deserialize_with = "deserialize_target")]
+        until: Option<String>,
+    },
+    /// Show a message in large letters.
+    Message {
+        text: String,
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+    },
+    /// Let the rain spell out a message, hold it and dissolve it again. Ends once the message
+    /// dissolved.
+    Spell {
+        text: String,
+        /// Time the assembled message is shown, which defaults to 5 seconds.
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        hold: Option<Duration>,
+        /// Spell out the message as plain text instead of large letters.
+        #[serde(default)]
+        plain: bool,
+    },
+    /// Show the current time of the day.
+    Clock {
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+    },
+    /// Start a new stopwatch.
+    Stopwatch {
+        #[serde(default, deserialize_with = "deserialize_duration")]
+        duration: Option<Duration>,
+    },
+}
+
+impl Scene {
+    /// Time after which the scene ends on its own, if it has one. Countdowns end once they reach
+    /// zero instead.
+    const fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::Countdown { .. } | Self::Spell { .. } => None,
+            Self::Intro { duration }
+            | Self::Rain { duration }
+            | Self::Message { duration, .. }
+            | Self::Clock { duration }
+            | Self::Stopwatch { duration } => *duration,
+        }
+    }
+}
+
+/// Effect played when switching from one scene to the next.
+#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Transition {
+    /// Switch immediately.
+    Cut,
+    /// Let the next scene emerge from scrambled characters.
+    #[default]
+    Dissolve,
+}
+
+/// Sequence of scenes as loaded from a playlist file.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PlaylistFile {
+    /// Start over with the first scene after the last one ended.
+    #[serde(rename = "loop")]
+    looping: bool,
+    transition: Transition,
+    /// Length of the transition, in milliseconds.
+    transition_time: u64,
+    scenes: Vec<Scene>,
+}
+
+impl Default for PlaylistFile {
+    fn default() -> Self {
+        Self {
+            looping: false,
+            transition: Transition::default(),
+            transition_time: 800,
+            scenes: Vec::new(),
+        }
+    }
+}
+
+/// Running sequence of scenes, keeping track of the current scene and how long it has been shown.
+pub struct Playlist {
+    scenes: Vec<Scene>,
+    looping: bool,
+    transition: Transition,
+    transition_time: Duration,
+    index: usize,
+    started: Instant,
+    /// Whether the current scene was switched to from another one, rather than being the first.
+    switched: bool,
+}
+
+impl Playlist {
+    /// Create a playlist that runs the given scenes once, without transitions.
+    pub fn new(scenes: Vec<Scene>) -> Self {
+        Self {
+            scenes,
+            looping: false,
+            transition: Transition::Cut,
+            transition_time: Duration::ZERO,
+            index: 0,
+            started: Instant::now(),
+            switched: false,
+        }
+    }
+
+    /// Play the given transition of the given length when switching between scenes.
+    pub fn with_transition(self, transition: Transition, time: Duration) -> Self {
+        Self {
+            transition,
+            transition_time: time,
+            ..self
+        }
+    }
+
+    /// Load a playlist from the given TOML file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed reading playlist from {}", path.display()))?;
+        Self::parse(&content)
+            .with_context(|| format!("failed parsing playlist from {}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let file = toml::from_str::<PlaylistFile>(content)?;
+        if file.scenes.is_empty() {
+            bail!("playlist doesn't contain any scenes");
+        }
+
+        Ok(Self {
+            looping: file.looping,
+            ..Self::new(file.scenes)
+                .with_transition(file.transition, Duration::from_millis(file.transition_time))
+        })
+    }
+
+    /// Whether any of the scenes matches the given predicate.
+    pub fn contains(&self, f: impl Fn(&Scene) -> bool) -> bool {
+        self.scenes.iter().any(f)
+    }
+
+    /// The scene that is currently shown.
+    pub fn current(&self) -> Option<&Scene> {
+        self.scenes.get(self.index)
+    }
+
+    /// Whether the current scene has been shown for its configured duration.
+    pub fn is_over(&self) -> bool {
+        self.current()
+            .and_then(Scene::duration)
+            .is_some_and(|duration| self.started.elapsed() >= duration)
+    }
+
+    /// Move on to the next scene. Returns `false` if the last scene is shown and the playlist
+    /// doesn't loop.
+    pub fn next(&mut self) -> bool {
+        let index = if self.index + 1 < self.scenes.len() {
+            self.index + 1
+        } else if self.looping && !self.scenes.is_empty() {
+            0
+        } else {
+            return false;
+        };
+
+        self.jump(index);
+        true
+    }
+
+    /// Go back to the previous scene. Returns `false` if the first scene is shown and the
+    /// playlist doesn't loop.
+    pub fn prev(&mut self) -> bool {
+        let index = if self.index > 0 {
+            self.index - 1
+        } else if self.looping && !self.scenes.is_empty() {
+            self.scenes.len() - 1
+        } else {
+            return false;
+        };
+
+        self.jump(index);
+        true
+    }
+
+    fn jump(&mut self, index: usize) {
+        self.index = index;
+        self.started = Instant::now();
+        self.switched = true;
+    }
+
+    /// Fraction of the transition into the current scene that is still left, from `1.0` right
+    /// after switching scenes down to `0.0` once it's complete. The first scene shows up without
+    /// a transition.
+    pub fn transition(&self) -> f32 {
+        if !self.switched || self.transition == Transition::Cut || self.transition_time.is_zero() {
+            return 0.0;
+        }
+
+        1.0 - (self.started.elapsed().as_secs_f32() / self.transition_time.as_secs_f32()).min(1.0)
+    }
+}
+
+/// Parse a duration made up of one or more amounts with a unit, like `90s`, `5m` or `1h30m`. A
+/// plain number is taken as seconds.
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = value;
+
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (amount, tail) = rest.split_at(end);
+        let unit_end = tail
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_end);
+
+        let Ok(amount) = amount.parse::<u64>() else {
+            bail!("invalid duration `{value}`, expected something like `90s`, `5m` or `1h30m`");
+        };
+
+        total += match unit.trim() {
+            "h" => Duration::from_secs(amount * 3600),
+            "m" => Duration::from_secs(amount * 60),
+            "s" => Duration::from_secs(amount),
+            "ms" => Duration::from_millis(amount),
+            _ => bail!("invalid duration `{value}`, expected units of `h`, `m`, `s` or `ms`"),
+        };
+        rest = tail.trim_start();
+    }
+
+    Ok(total)
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| parse_duration(&value).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Check that the countdown target is valid, but keep it as is to be resolved later.
+fn deserialize_target<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    if let Some(value) = &value {
+        parse_target(value).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_duration, Playlist, Scene};
+
+    #[test]
+    fn durations() {
+        assert_eq!(Duration::from_secs(10), parse_duration("10").unwrap());
+        assert_eq!(Duration::from_secs(300), parse_duration("5m").unwrap());
+        assert_eq!(Duration::from_secs(5400), parse_duration("1h30m").unwrap());
+        assert_eq!(
+            Duration::from_millis(1500),
+            parse_duration("1s 500ms").unwrap()
+        );
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("5d").is_err());
+    }
+
+    #[test]
+    fn playlist() {
+        let mut playlist = Playlist::parse(
+            r#"
+            loop = true
+
+            [[scenes]]
+            type = "intro"
+            duration = "10s"
+
+            [[scenes]]
+            type = "countdown"
+            duration = "5m"
+
+            [[scenes]]
+            type = "message"
+            text = "Starting"
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(playlist.current(), Some(Scene::Intro { .. })));
+        assert!(playlist.next());
+        assert!(matches!(
+            playlist.current(),
+            Some(Scene::Countdown { duration: Some(d), until: None }) if d.as_secs() == 300
+        ));
+        assert!(playlist.next());
+        assert!(playlist.next());
+        assert!(matches!(playlist.current(), Some(Scene::Intro { .. })));
+        assert!(playlist.prev());
+        assert!(
+            matches!(playlist.current(), Some(Scene::Message { text, .. }) if text == "Starting")
+        );
+
+        assert!(Playlist::parse("loop = true").is_err());
+        assert!(Playlist::parse("[[scenes]]\ntype = 'countdown'\nuntil = 'soon'").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+//! Names taken from the commit history of a local Git repository.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::NameSource;
+use crate::names::Name;
+
+/// Part of each commit that is shown in the rain.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum GitField {
+    /// Name of the commit author, each author only listed once.
+    #[default]
+    Authors,
+    /// First line of the commit message.
+    Subjects,
+    /// Abbreviated commit hash.
+    Hashes,
+}
+
+impl GitField {
+    const fn placeholder(self) -> &'static str {
+        match self {
+            Self::Authors => "%an",
+            Self::Subjects => "%s",
+            Self::Hashes => "%h",
+        }
+    }
+}
+
+/// Filters that limit which commits are read from the history.
+#[derive(Clone, Copy, Default)]
+pub struct GitFilter<'a> {
+    /// Branch, tag or any other revision to read the history of, instead of `HEAD`.
+    pub branch: Option<&'a str>,
+    /// Only include commits after this date, in any format that Git understands.
+    pub since: Option<&'a str>,
+    /// Only include commits before this date, in any format that Git understands.
+    pub until: Option<&'a str>,
+}
+
+/// Read the authors, subjects or hashes of all commits in the repository at the given path, newest
+/// first. Requires the `git` executable to be installed.
+pub fn log(path: &Path, field: GitField, filter: GitFilter<'_>) -> Result<Vec<String>> {
+    // Git would take the branch for an option otherwise, like `--output=<file>`. Names of branches
+    // and tags can't start with a dash anyway.
+    if let Some(branch) = filter.branch.filter(|branch| branch.starts_with('-')) {
+        bail!("invalid branch {branch:?}, it must not start with `-`");
+    }
+
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(path)
+        .arg("log")
+        .arg(format!("--format={}", field.placeholder()));
+
+    if let Some(since) = filter.since {
+        cmd.arg(format!("--since={since}"));
+    }
+    if let Some(until) = filter.until {
+        cmd.arg(format!("--until={until}"));
+    }
+    cmd.args(filter.branch).arg("--");
+
+    let output = cmd
+        .output()
+        .context("failed running git, is it installed?")?;
+    if !output.status.success() {
+        bail!(
+            "failed reading the history of {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let mut seen = HashSet::new();
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| field != GitField::Authors || seen.insert(*line))
+        .map(ToOwned::to_owned)
+        .collect())
+}
+
+/// History of a local Git repository.
+pub struct GitSource {
+    pub path: PathBuf,
+    pub field: GitField,
+    pub branch: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+}
+
+impl NameSource for GitSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        let filter = GitFilter {
+            branch: self.branch.as_deref(),
+            since: self.since.as_deref(),
+            until: self.until.as_deref(),
+        };
+
+        Ok(log(&self.path, self.field, filter)?
+            .into_iter()
+            .map(Name::new)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::ErrorKind,
+        process::Command,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use super::{log, GitField, GitFilter};
+
+    #[test]
+    fn repository_history() {
+        // Skip the test entirely where Git isn't available.
+        if let Err(e) = Command::new("git").arg("--version").output() {
+            assert_eq!(ErrorKind::NotFound, e.kind());
+            return;
+        }
+
+        // A fresh directory for every run, as a failed run may leave its repository behind.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = env::temp_dir().join(format!("rsmatrix-git-{}-{nanos}", std::process::id()));
+        fs::create_dir(&dir).unwrap();
+
+        let git = |args: &[&str], author: &str, date: &str| {
+            Command::new("git")
+                .arg("-C")
+                .arg(&dir)
+                .args(args)
+                // Settings of the user, like signing commits, must not affect the test.
+                .env("GIT_CONFIG_GLOBAL", "/dev/null")
+                .env("GIT_CONFIG_NOSYSTEM", "1")
+                .env("GIT_AUTHOR_NAME", author)
+                .env("GIT_AUTHOR_EMAIL", "rain@example.com")
+                .env("GIT_COMMITTER_NAME", author)
+                .env("GIT_COMMITTER_EMAIL", "rain@example.com")
+                .env("GIT_AUTHOR_DATE", date)
+                .env("GIT_COMMITTER_DATE", date)
+                .status()
+                .unwrap()
+                .success()
+        };
+        let commit = |author: &str, subject: &str, date: &str| {
+            git(
+                &["commit", "--allow-empty", "-q", "-m", subject],
+                author,
+                date,
+            )
+        };
+
+        // Older versions of Git don't support `init -b` to name the initial branch.
+        assert!(git(&["init", "-q"], "", "2024-01-01T00:00:00"));
+        assert!(git(
+            &["symbolic-ref", "HEAD", "refs/heads/main"],
+            "",
+            "2024-01-01T00:00:00"
+        ));
+        assert!(commit("neo", "Wake up", "2024-01-01T12:00:00"));
+        assert!(commit(
+            "trinity",
+            "Follow the rabbit",
+            "2024-02-01T12:00:00"
+        ));
+        assert!(commit("neo", "Take the red pill", "2024-03-01T12:00:00"));
+
+        let all = GitFilter::default();
+        assert_eq!(
+            vec!["neo", "trinity"],
+            log(&dir, GitField::Authors, all).unwrap()
+        );
+        assert_eq!(
+            vec!["Follow the rabbit"],
+            log(
+                &dir,
+                GitField::Subjects,
+                GitFilter {
+                    branch: Some("main"),
+                    since: Some("2024-01-15"),
+                    until: Some("2024-02-15"),
+                }
+            )
+            .unwrap()
+        );
+        assert_eq!(3, log(&dir, GitField::Hashes, all).unwrap().len());
+        assert!(log(
+            &dir,
+            GitField::Authors,
+            GitFilter {
+                branch: Some("missing"),
+                ..all
+            }
+        )
+        .is_err());
+        assert!(log(
+            &dir,
+            GitField::Authors,
+            GitFilter {
+                branch: Some("--output=rain.txt"),
+                ..all
+            }
+        )
+        .is_err());
+        assert!(!dir.join("rain.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
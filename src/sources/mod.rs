@@ -0,0 +1,418 @@
+//! Sources of names for the rain, that are either loaded once or kept up to date in the background.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{self, prelude::*, BufReader},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+
+#[cfg(feature = "http")]
+use self::structured::HttpSource;
+#[cfg(feature = "twitch")]
+use self::twitch::TwitchSource;
+pub use self::{git::GitField, structured::Format, tail::appended};
+use self::{
+    git::GitSource,
+    structured::StructuredFile,
+    system::{DirSource, ProcessSource},
+    tail::{Tail, TailOptions},
+};
+use crate::{names::Name, Source};
+
+mod git;
+mod structured;
+mod system;
+mod tail;
+#[cfg(feature = "twitch")]
+mod twitch;
+
+/// Provider of names for the rain.
+pub trait NameSource: Send {
+    /// Load the current list of names.
+    fn load(&mut self) -> Result<Vec<Name>>;
+
+    /// Time after which the names are loaded again, if the source is polled.
+    fn interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Keep reporting updated lists of names, until the report function returns `false`. Errors
+    /// are reported as well, without ending the updates.
+    ///
+    /// By default, the names are loaded again after every interval, and sources without an
+    /// interval return right away. Failed loads are retried sooner, with growing delays. Sources
+    /// that know when they change, like growing log files, report their updates as they happen
+    /// instead.
+    fn watch(&mut self, report: &mut dyn FnMut(Result<Vec<Name>>) -> bool) {
+        let Some(interval) = self.interval() else {
+            return;
+        };
+
+        loop {
+            thread::sleep(interval);
+            let names = self.load();
+            let failed = names.is_err();
+
+            if !report(names) || (failed && !retry(self, report)) {
+                break;
+            }
+        }
+    }
+}
+
+/// Longest delay between retries of a failing source.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Delay before loading a source again, that failed the given amount of times in a row. It starts
+/// at one second and doubles with every failure.
+pub fn backoff(failures: u32) -> Duration {
+    Duration::from_secs(1 << failures.saturating_sub(1).min(6)).min(MAX_BACKOFF)
+}
+
+/// Load the names of a failed source again with growing delays until it succeeds, but never wait
+/// longer than its regular interval. Returns whether the updates should continue.
+fn retry<S: NameSource + ?Sized>(
+    source: &mut S,
+    report: &mut dyn FnMut(Result<Vec<Name>>) -> bool,
+) -> bool {
+    for failures in 1.. {
+        let delay = backoff(failures);
+        thread::sleep(
+            source
+                .interval()
+                .map_or(delay, |interval| delay.min(interval)),
+        );
+
+        let names = source.load();
+        let failed = names.is_err();
+
+        if !report(names) {
+            return false;
+        }
+        if !failed {
+            break;
+        }
+    }
+
+    true
+}
+
+/// Create the name source for the given settings.
+pub fn open(source: &Source) -> Result<Box<dyn NameSource>> {
+    Ok(match source {
+        Source::File { path } => Box::new(FileSource { path: path.clone() }),
+        #[cfg(feature = "twitch")]
+        Source::Twitch { username } => Box::new(TwitchSource {
+            username: username.clone(),
+        }),
+        Source::Json { path, select } => Box::new(StructuredFile {
+            path: path.clone(),
+            format: Format::Json,
+            selector: select.clone(),
+        }),
+        Source::Csv { path, column } => Box::new(StructuredFile {
+            path: path.clone(),
+            format: Format::Csv,
+            selector: column.clone(),
+        }),
+        Source::Git {
+            path,
+            show,
+            branch,
+            since,
+            until,
+        } => Box::new(GitSource {
+            path: path.clone(),
+            field: *show,
+            branch: branch.clone(),
+            since: since.clone(),
+            until: until.clone(),
+        }),
+        Source::Dir {
+            path,
+            glob,
+            depth,
+            interval,
+        } => Box::new(DirSource {
+            path: path.clone(),
+            globs: glob.clone(),
+            depth: *depth,
+            interval: interval.map_or(DirSource::DEFAULT_INTERVAL, seconds),
+        }),
+        Source::Processes { interval } => Box::new(ProcessSource {
+            interval: interval.map_or(ProcessSource::DEFAULT_INTERVAL, seconds),
+        }),
+        Source::Tail {
+            path,
+            extract,
+            alert,
+            alert_color,
+            keep,
+        } => Box::new(Tail::new(
+            path,
+            TailOptions::new(extract.as_deref(), alert, *alert_color, *keep)?,
+        )),
+        #[cfg(feature = "http")]
+        Source::Http {
+            url,
+            format,
+            select,
+            interval,
+        } => Box::new(HttpSource {
+            url: url.clone(),
+            format: *format,
+            selector: select.clone(),
+            interval: interval.map_or(HttpSource::DEFAULT_INTERVAL, seconds),
+        }),
+        Source::Stdin => Box::new(StdinSource),
+        Source::List { names } => Box::new(ListSource {
+            names: names.clone(),
+        }),
+    })
+}
+
+/// Convert an interval in seconds, with a lower limit of one second.
+fn seconds(secs: u64) -> Duration {
+    Duration::from_secs(secs.max(1))
+}
+
+/// Plain text file with one name per line.
+struct FileSource {
+    path: PathBuf,
+}
+
+impl NameSource for FileSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        let file = File::open(&self.path)
+            .with_context(|| format!("failed reading names from {}", self.path.display()))?;
+
+        BufReader::new(file)
+            .lines()
+            .map(|line| line.map(Name::new).map_err(Into::into))
+            .collect()
+    }
+}
+
+/// Lines read from the standard input, until it's closed.
+struct StdinSource;
+
+impl NameSource for StdinSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        io::stdin()
+            .lines()
+            .map(|line| line.map(Name::new).map_err(Into::into))
+            .collect()
+    }
+}
+
+/// Fixed list of names.
+struct ListSource {
+    names: Vec<String>,
+}
+
+impl NameSource for ListSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        Ok(self.names.iter().cloned().map(Name::new).collect())
+    }
+}
+
+/// Update of a watched source, with its slot and the flag telling whether it's still watched.
+type Update = (usize, Arc<AtomicBool>, Result<Vec<Name>>);
+
+/// Watches name sources in the background, each in its own numbered slot, and keeps track of
+/// their errors.
+pub struct Watcher {
+    tx: Sender<Update>,
+    rx: Receiver<Update>,
+    /// Flags that keep the current source of each slot running.
+    active: HashMap<usize, Arc<AtomicBool>>,
+    /// Last error of each slot, until its source loads successfully again.
+    errors: BTreeMap<usize, String>,
+}
+
+impl Default for Watcher {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            tx,
+            rx,
+            active: HashMap::new(),
+            errors: BTreeMap::new(),
+        }
+    }
+}
+
+impl Watcher {
+    /// Load the names of the source and keep watching it in the background, replacing the
+    /// previous source of the same slot. If loading fails, the error is kept and the source is
+    /// retried in the background, starting out without any names.
+    pub fn start(&mut self, slot: usize, mut source: Box<dyn NameSource>) -> Vec<Name> {
+        match source.load() {
+            Ok(names) => {
+                self.watch(slot, source, false);
+                names
+            }
+            Err(e) => {
+                self.watch(slot, source, true);
+                self.errors.insert(slot, format!("{e:#}"));
+                Vec::new()
+            }
+        }
+    }
+
+    /// Like [`Self::start`], but a source that fails to load is rejected instead, keeping the
+    /// previous source of the slot.
+    pub fn try_start(&mut self, slot: usize, mut source: Box<dyn NameSource>) -> Result<Vec<Name>> {
+        let names = source.load()?;
+        self.watch(slot, source, false);
+        Ok(names)
+    }
+
+    fn watch(&mut self, slot: usize, mut source: Box<dyn NameSource>, failed: bool) {
+        self.stop(slot);
+
+        let active = Arc::new(AtomicBool::new(true));
+        self.active.insert(slot, Arc::clone(&active));
+        let tx = self.tx.clone();
+
+        thread::spawn(move || {
+            let report = &mut |names| {
+                active.load(Ordering::Relaxed)
+                    && tx.send((slot, Arc::clone(&active), names)).is_ok()
+            };
+
+            if !failed || retry(source.as_mut(), report) {
+                source.watch(report);
+            }
+        });
+    }
+
+    /// Stop watching the source of the given slot, and forget its error.
+    pub fn stop(&mut self, slot: usize) {
+        if let Some(active) = self.active.remove(&slot) {
+            active.store(false, Ordering::Relaxed);
+        }
+        self.errors.remove(&slot);
+    }
+
+    /// Next list of names loaded by any watched source, if there is one. Updates of sources that
+    /// were replaced or stopped in the meantime are skipped, and errors are kept until the same
+    /// source loads successfully again.
+    pub fn try_recv(&mut self) -> Option<(usize, Vec<Name>)> {
+        self.next(|rx| rx.try_recv().ok())
+    }
+
+    /// Next list of names, taking updates from the channel with the given function until there
+    /// is none left.
+    fn next(
+        &mut self,
+        recv: impl Fn(&Receiver<Update>) -> Option<Update>,
+    ) -> Option<(usize, Vec<Name>)> {
+        loop {
+            let (slot, active, names) = recv(&self.rx)?;
+            if !active.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            match names {
+                Ok(names) => {
+                    self.errors.remove(&slot);
+                    return Some((slot, names));
+                }
+                Err(e) => {
+                    self.errors.insert(slot, format!("{e:#}"));
+                }
+            }
+        }
+    }
+
+    /// Current errors of all failing sources, by slot.
+    pub fn errors(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.errors.iter().map(|(slot, e)| (*slot, e.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::{bail, Result};
+
+    use super::{backoff, NameSource, Watcher};
+    use crate::names::Name;
+
+    struct Counter {
+        count: usize,
+    }
+
+    impl NameSource for Counter {
+        fn load(&mut self) -> Result<Vec<Name>> {
+            self.count += 1;
+            Ok(vec![Name::new(self.count.to_string())])
+        }
+
+        fn interval(&self) -> Option<Duration> {
+            Some(Duration::from_millis(10))
+        }
+    }
+
+    struct Offline;
+
+    impl NameSource for Offline {
+        fn load(&mut self) -> Result<Vec<Name>> {
+            bail!("offline")
+        }
+    }
+
+    /// Wait for the next update, instead of only taking one that is already there.
+    fn recv(watcher: &mut Watcher) -> Option<(usize, Vec<Name>)> {
+        watcher.next(|rx| rx.recv_timeout(Duration::from_secs(5)).ok())
+    }
+
+    #[test]
+    fn watch_and_replace() {
+        let mut watcher = Watcher::default();
+        assert_eq!(
+            "1",
+            watcher.start(1, Box::new(Counter { count: 0 }))[0].text
+        );
+        let (slot, names) = recv(&mut watcher).unwrap();
+        assert_eq!(1, slot);
+        assert_eq!("2", names[0].text);
+
+        // Pending updates of the replaced source are dropped.
+        watcher.start(1, Box::new(Counter { count: 100 }));
+        let (_, names) = recv(&mut watcher).unwrap();
+        assert_eq!("102", names[0].text);
+
+        watcher.stop(1);
+        assert!(watcher.try_recv().is_none());
+    }
+
+    #[test]
+    fn failing_sources() {
+        assert_eq!(Duration::from_secs(1), backoff(1));
+        assert_eq!(Duration::from_secs(8), backoff(4));
+        assert_eq!(Duration::from_secs(60), backoff(100));
+
+        let mut watcher = Watcher::default();
+        assert!(watcher.try_start(0, Box::new(Offline)).is_err());
+        assert_eq!(0, watcher.errors().count());
+
+        assert!(watcher.start(0, Box::new(Offline)).is_empty());
+        assert_eq!(vec![(0, "offline")], watcher.errors().collect::<Vec<_>>());
+
+        watcher.stop(0);
+        assert_eq!(0, watcher.errors().count());
+    }
+}
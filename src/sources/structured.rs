@@ -0,0 +1,276 @@
+//! Extraction of names from structured data like JSON and CSV, either from files or over HTTP.
+
+#[cfg(feature = "http")]
+use std::time::Duration;
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::NameSource;
+use crate::names::Name;
+
+/// Format of the data that names are extracted from.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Plain text with one name per line.
+    Lines,
+    /// JSON document, with the names picked by a selector.
+    Json,
+    /// CSV table with a header row, with the names taken from a single column.
+    Csv,
+}
+
+/// Extract the names from the content in the given format. The selector picks the values out of
+/// JSON documents, or the column of CSV tables, and is ignored for plain text.
+pub fn parse(content: &str, format: Format, selector: Option<&str>) -> Result<Vec<String>> {
+    match format {
+        Format::Lines => Ok(content.lines().map(ToOwned::to_owned).collect()),
+        Format::Json => {
+            let value = serde_json::from_str(content).context("invalid JSON")?;
+            select_json(&value, selector.unwrap_or_default())
+        }
+        Format::Csv => select_csv(content, selector.unwrap_or("0")),
+    }
+}
+
+/// Pick values out of a JSON document with a JSONPath-like selector, like `$.data[*].name`.
+///
+/// The selector is a list of object keys separated by dots, where each key can be followed by
+/// `[*]` (or `[]`) to continue with all elements of an array, or `[n]` for a single element. The
+/// leading `$` is optional. Selected strings are used as is, numbers and booleans are converted to
+/// text and arrays of them are flattened.
+pub fn select_json(value: &Value, selector: &str) -> Result<Vec<String>> {
+    let selector = selector.trim();
+    let selector = selector.strip_prefix('$').unwrap_or(selector);
+    let mut current = vec![value];
+
+    for segment in selector.split('.').filter(|s| !s.is_empty()) {
+        let (key, mut indices) = segment
+            .find('[')
+            .map_or((segment, ""), |pos| segment.split_at(pos));
+
+        if !key.is_empty() {
+            current = current.into_iter().filter_map(|v| v.get(key)).collect();
+        }
+
+        while let Some(rest) = indices.strip_prefix('[') {
+            let Some((index, tail)) = rest.split_once(']') else {
+                bail!("unclosed `[` in selector `{selector}`");
+            };
+            indices = tail;
+
+            current = match index.trim() {
+                "" | "*" => current
+                    .into_iter()
+                    .flat_map(|v| match v {
+                        Value::Array(values) => values.iter().collect(),
+                        Value::Object(values) => values.values().collect(),
+                        _ => Vec::new(),
+                    })
+                    .collect(),
+                index => {
+                    let index = index
+                        .parse::<usize>()
+                        .with_context(|| format!("invalid index `{index}` in selector"))?;
+                    current.into_iter().filter_map(|v| v.get(index)).collect()
+                }
+            };
+        }
+    }
+
+    Ok(current.into_iter().flat_map(json_to_strings).collect())
+}
+
+/// Convert a selected JSON value into names.
+fn json_to_strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(s) => vec![s.clone()],
+        Value::Number(n) => vec![n.to_string()],
+        Value::Bool(b) => vec![b.to_string()],
+        Value::Array(values) => values.iter().flat_map(json_to_strings).collect(),
+        Value::Null | Value::Object(_) => Vec::new(),
+    }
+}
+
+/// Take all values of a single column from a CSV table. The column is either the name of a
+/// column in the header row, or its zero-based position.
+pub fn select_csv(content: &str, column: &str) -> Result<Vec<String>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let headers = reader.headers().context("invalid CSV")?;
+    let Some(index) = headers
+        .iter()
+        .position(|header| header.trim() == column)
+        .or_else(|| column.parse().ok())
+    else {
+        bail!("CSV doesn't contain a column named `{column}`");
+    };
+
+    reader
+        .records()
+        .filter_map(|record| {
+            record
+                .map(|record| record.get(index).map(ToOwned::to_owned))
+                .context("invalid CSV")
+                .transpose()
+        })
+        .collect()
+}
+
+/// Download names from the given URL. Without an explicit format, it's guessed from the content
+/// type of the response.
+#[cfg(feature = "http")]
+pub fn fetch(url: &str, format: Option<Format>, selector: Option<&str>) -> Result<Vec<String>> {
+    let response = ureq::get(url)
+        .call()
+        .with_context(|| format!("failed requesting {url}"))?;
+
+    let format = format.unwrap_or_else(|| {
+        let content_type = response.content_type();
+        if content_type.contains("json") {
+            Format::Json
+        } else if content_type.contains("csv") {
+            Format::Csv
+        } else {
+            Format::Lines
+        }
+    });
+    let content = response.into_string()?;
+
+    parse(&content, format, selector).with_context(|| format!("failed parsing names from {url}"))
+}
+
+/// JSON or CSV file.
+pub struct StructuredFile {
+    pub path: PathBuf,
+    pub format: Format,
+    pub selector: Option<String>,
+}
+
+impl NameSource for StructuredFile {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("failed reading names from {}", self.path.display()))?;
+
+        Ok(parse(&content, self.format, self.selector.as_deref())
+            .with_context(|| format!("failed parsing names from {}", self.path.display()))?
+            .into_iter()
+            .map(Name::new)
+            .collect())
+    }
+}
+
+/// Names downloaded from a URL.
+#[cfg(feature = "http")]
+pub struct HttpSource {
+    pub url: String,
+    pub format: Option<Format>,
+    pub selector: Option<String>,
+    pub interval: Duration,
+}
+
+#[cfg(feature = "http")]
+impl HttpSource {
+    /// Time after which the names are downloaded again, if not configured otherwise.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+}
+
+#[cfg(feature = "http")]
+impl NameSource for HttpSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        Ok(fetch(&self.url, self.format, self.selector.as_deref())?
+            .into_iter()
+            .map(Name::new)
+            .collect())
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        Some(self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{parse, select_csv, select_json, Format};
+
+    #[test]
+    fn json_selectors() {
+        let value = json!({
+            "data": [
+                { "name": "neo", "id": 1 },
+                { "name": "trinity", "id": 2 },
+            ],
+            "chatters": { "vips": ["morpheus"], "viewers": ["tank", "dozer"] },
+        });
+
+        assert_eq!(
+            vec!["neo", "trinity"],
+            select_json(&value, "$.data[*].name").unwrap()
+        );
+        assert_eq!(vec!["2"], select_json(&value, "data[1].id").unwrap());
+        assert_eq!(
+            vec!["tank", "dozer", "morpheus"],
+            select_json(&value, "chatters[*]").unwrap()
+        );
+        assert_eq!(
+            vec!["tank"],
+            select_json(&value, "chatters.viewers[0]").unwrap()
+        );
+        assert!(select_json(&value, "missing").unwrap().is_empty());
+        assert!(select_json(&value, "data[x]").is_err());
+    }
+
+    #[test]
+    fn csv_columns() {
+        let content = "id,login,score\n1,neo,10\n2,trinity,20\n";
+
+        assert_eq!(
+            vec!["neo", "trinity"],
+            select_csv(content, "login").unwrap()
+        );
+        assert_eq!(vec!["10", "20"], select_csv(content, "2").unwrap());
+        assert!(select_csv(content, "missing").is_err());
+        assert_eq!(vec!["1", "2"], parse(content, Format::Csv, None).unwrap());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn http_source() {
+        use std::{
+            io::{Read, Write},
+            net::TcpListener,
+            thread,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/names", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+
+            let body = r#"{"users":[{"login":"neo"},{"login":"trinity"}]}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: \
+                 {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let names = super::fetch(&url, None, Some("users[*].login")).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(vec!["neo", "trinity"], names);
+    }
+}
@@ -0,0 +1,186 @@
+//! Names taken from the local system, like files in a directory or running processes.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use regex::RegexSet;
+
+use super::NameSource;
+use crate::names::Name;
+
+/// Collect the names of all files below the given directory that match any of the glob patterns,
+/// or all files if there are none. Patterns are matched against the file name only, and support
+/// `*` for any amount of characters and `?` for a single one.
+///
+/// The depth limits how many levels of sub-directories are visited, where `0` only includes the
+/// files directly in the directory.
+pub fn walk(path: &Path, globs: &[String], depth: Option<usize>) -> Result<Vec<String>> {
+    let patterns = RegexSet::new(globs.iter().map(|glob| glob_to_regex(glob)))
+        .context("invalid glob pattern")?;
+    let mut names = Vec::new();
+
+    walk_dir(path, &patterns, depth, &mut names)
+        .with_context(|| format!("failed listing files in {}", path.display()))?;
+
+    Ok(names)
+}
+
+fn walk_dir(
+    path: &Path,
+    patterns: &RegexSet,
+    depth: Option<usize>,
+    names: &mut Vec<String>,
+) -> Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            let depth = match depth {
+                None => None,
+                Some(0) => continue,
+                Some(depth) => Some(depth - 1),
+            };
+            // Symbolic links aren't followed, so there is no risk of running in circles.
+            // Directories that can't be read, like ones without permission, are skipped.
+            walk_dir(&entry.path(), patterns, depth, names).ok();
+        } else {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if patterns.is_empty() || patterns.is_match(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert a glob pattern into an equivalent regular expression that matches the whole text.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// Collect the names of all currently running processes from `/proc`, which is only available on
+/// Linux.
+pub fn processes() -> Result<Vec<String>> {
+    let mut names = Vec::new();
+
+    for entry in fs::read_dir("/proc").context("failed listing processes from /proc")? {
+        let entry = entry?;
+        let is_pid = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.bytes().all(|b| b.is_ascii_digit()));
+        if !is_pid {
+            continue;
+        }
+
+        // Processes may exit while they're listed, so unreadable ones are skipped.
+        if let Ok(name) = fs::read_to_string(entry.path().join("comm")) {
+            names.push(name.trim_end().to_owned());
+        }
+    }
+
+    Ok(names)
+}
+
+/// Files in a directory and its sub-directories.
+pub struct DirSource {
+    pub path: PathBuf,
+    pub globs: Vec<String>,
+    pub depth: Option<usize>,
+    pub interval: Duration,
+}
+
+impl DirSource {
+    /// Time after which the files are listed again, if not configured otherwise.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+}
+
+impl NameSource for DirSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        Ok(walk(&self.path, &self.globs, self.depth)?
+            .into_iter()
+            .map(Name::new)
+            .collect())
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        Some(self.interval)
+    }
+}
+
+/// Currently running processes.
+pub struct ProcessSource {
+    pub interval: Duration,
+}
+
+impl ProcessSource {
+    /// Time after which the processes are listed again, if not configured otherwise.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+}
+
+impl NameSource for ProcessSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        Ok(processes()?.into_iter().map(Name::new).collect())
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        Some(self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::walk;
+
+    #[test]
+    fn walk_with_globs_and_depth() {
+        let dir = env::temp_dir().join(format!("rsmatrix-walk-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src/matrix")).unwrap();
+        for file in [
+            "Cargo.toml",
+            "src/main.rs",
+            "src/matrix/mod.rs",
+            "src/x.rs.bak",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let mut names = walk(&dir, &["*.rs".to_owned(), "*.toml".to_owned()], None).unwrap();
+        names.sort();
+        assert_eq!(vec!["Cargo.toml", "main.rs", "mod.rs"], names);
+
+        let mut names = walk(&dir, &[], Some(1)).unwrap();
+        names.sort();
+        assert_eq!(vec!["Cargo.toml", "main.rs", "x.rs.bak"], names);
+
+        assert_eq!(
+            vec!["Cargo.toml"],
+            walk(&dir, &["Cargo.?oml".to_owned()], Some(0)).unwrap()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn running_processes() {
+        assert!(!super::processes().unwrap().is_empty());
+    }
+}
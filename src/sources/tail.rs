@@ -0,0 +1,393 @@
+//! Following of log files, similar to `tail -F`.
+
+use std::{
+    collections::VecDeque,
+    fs::{self, File},
+    io::{prelude::*, BufReader, SeekFrom},
+    mem,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use regex::{Regex, RegexSet};
+
+use super::{retry, NameSource};
+use crate::names::Name;
+
+/// Time between checks of the log file for new lines.
+const POLL_TIME: Duration = Duration::from_millis(250);
+/// Amount of data read from the end of the log file when it's first opened.
+const INITIAL_READ: u64 = 64 * 1024;
+/// Length in bytes after which lines are cut off.
+const MAX_LINE: usize = 4 * 1024;
+
+/// Settings for turning log lines into names.
+pub struct TailOptions {
+    /// Pattern to pick values from each line. All capture groups become separate names, or the
+    /// whole match if there are none.
+    pub extract: Option<Regex>,
+    /// Patterns that mark lines as alerts.
+    pub alert: RegexSet,
+    /// Color of names from alert lines.
+    pub alert_color: u8,
+    /// Amount of most recent names that are kept.
+    pub keep: usize,
+}
+
+impl TailOptions {
+    pub const DEFAULT_ALERT_COLOR: u8 = 196;
+    pub const DEFAULT_KEEP: usize = 100;
+
+    /// Compile the given extraction and alert patterns.
+    pub fn new(
+        extract: Option<&str>,
+        alert: &[String],
+        alert_color: Option<u8>,
+        keep: Option<usize>,
+    ) -> Result<Self> {
+        Ok(Self {
+            extract: extract
+                .map(Regex::new)
+                .transpose()
+                .context("invalid extraction pattern")?,
+            alert: RegexSet::new(alert).context("invalid alert pattern")?,
+            alert_color: alert_color.unwrap_or(Self::DEFAULT_ALERT_COLOR),
+            keep: keep.unwrap_or(Self::DEFAULT_KEEP).max(1),
+        })
+    }
+}
+
+/// Log file that is followed by name, so it's opened again when it gets rotated or truncated.
+pub struct Tail {
+    path: PathBuf,
+    options: TailOptions,
+    /// Currently followed file, once it could be opened.
+    file: Option<File>,
+    /// Identity of the opened file, to notice when it's replaced by a new one.
+    id: Option<(u64, u64)>,
+    /// Position up to which the file was read.
+    pos: u64,
+    /// Incomplete last line, that is continued with the next read. It's only decoded once it's
+    /// complete, as a read may end in the middle of a character.
+    partial: Vec<u8>,
+    /// Whether the incomplete last line was cut off, so the rest of it is skipped.
+    overlong: bool,
+    names: VecDeque<Name>,
+}
+
+impl Tail {
+    /// Prepare following the log file. It's opened with the first poll.
+    pub fn new(path: &Path, options: TailOptions) -> Self {
+        Self {
+            path: path.to_owned(),
+            options,
+            file: None,
+            id: None,
+            pos: 0,
+            partial: Vec::new(),
+            overlong: false,
+            names: VecDeque::new(),
+        }
+    }
+
+    /// Most recent names, oldest first.
+    pub fn names(&self) -> Vec<Name> {
+        self.names.iter().cloned().collect()
+    }
+
+    /// Read any new lines, and check whether the file was rotated or truncated. Returns whether
+    /// the names changed.
+    pub fn poll(&mut self) -> Result<bool> {
+        if self.file.is_none() {
+            return self.open();
+        }
+
+        let mut changed = self.read()?;
+
+        // A missing file is most likely in the middle of being rotated, so it's checked again
+        // with the next poll.
+        let Ok(metadata) = fs::metadata(&self.path) else {
+            return Ok(changed);
+        };
+
+        if file_id(&metadata) != self.id || metadata.len() < self.pos {
+            self.file = Some(
+                File::open(&self.path)
+                    .with_context(|| format!("failed opening {}", self.path.display()))?,
+            );
+            self.id = file_id(&metadata);
+            self.pos = 0;
+            self.partial.clear();
+            self.overlong = false;
+            changed |= self.read()?;
+        }
+
+        Ok(changed)
+    }
+
+    /// Open the file for the first time, and take the names from its last lines.
+    fn open(&mut self) -> Result<bool> {
+        let file = File::open(&self.path)
+            .with_context(|| format!("failed opening {}", self.path.display()))?;
+        let metadata = file.metadata()?;
+        let mut start = metadata.len().saturating_sub(INITIAL_READ);
+
+        // The first line is most likely cut off when starting in the middle of the file, so
+        // everything up to its end is skipped.
+        if start > 0 {
+            let mut reader = BufReader::new(&file);
+            reader.seek(SeekFrom::Start(start))?;
+            start += reader.read_until(b'\n', &mut Vec::new())? as u64;
+        }
+
+        self.file = Some(file);
+        self.id = file_id(&metadata);
+        self.pos = start;
+        self.partial.clear();
+        self.overlong = false;
+        self.read()?;
+
+        Ok(true)
+    }
+
+    /// Read from the current file up to its end. Returns whether any new names were added.
+    fn read(&mut self) -> Result<bool> {
+        let Some(file) = &mut self.file else {
+            return Ok(false);
+        };
+
+        let start = self.partial.len();
+        file.seek(SeekFrom::Start(self.pos))?;
+        self.pos += file.read_to_end(&mut self.partial)? as u64;
+
+        if self.overlong {
+            match self.partial[start..].iter().position(|&b| b == b'\n') {
+                Some(end) => {
+                    self.partial.drain(start..start + end);
+                    self.overlong = false;
+                }
+                None => {
+                    self.partial.truncate(start);
+                    return Ok(false);
+                }
+            }
+        }
+
+        let end = self
+            .partial
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |end| end + 1);
+        let rest = self.partial.split_off(end);
+        let complete = mem::replace(&mut self.partial, rest);
+
+        // A line without an end in sight would take up memory without limit otherwise.
+        if self.partial.len() > MAX_LINE {
+            self.partial.truncate(MAX_LINE);
+            self.overlong = true;
+        }
+
+        let mut added = false;
+        for line in String::from_utf8_lossy(&complete).lines() {
+            added |= self.push_line(line);
+        }
+
+        Ok(added)
+    }
+
+    /// Turn a single line into names. Returns whether any names were added.
+    fn push_line(&mut self, line: &str) -> bool {
+        let alert = self.options.alert.is_match(line);
+        let texts = self.options.extract.as_ref().map_or_else(
+            || vec![line.to_owned()],
+            |extract| {
+                extract
+                    .captures_iter(line)
+                    .flat_map(|caps| {
+                        let skip = usize::from(caps.len() > 1);
+                        caps.iter()
+                            .skip(skip)
+                            .flatten()
+                            .map(|m| m.as_str().to_owned())
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            },
+        );
+
+        let mut added = false;
+        for text in texts.into_iter().filter(|text| !text.trim().is_empty()) {
+            let name = Name::new(text);
+            self.names.push_back(if alert {
+                name.alert(self.options.alert_color)
+            } else {
+                name
+            });
+            added = true;
+        }
+
+        while self.names.len() > self.options.keep {
+            self.names.pop_front();
+        }
+
+        added
+    }
+}
+
+impl NameSource for Tail {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        self.poll()?;
+        Ok(self.names())
+    }
+
+    /// Follow the file forever, reporting the new names whenever they change.
+    fn watch(&mut self, report: &mut dyn FnMut(Result<Vec<Name>>) -> bool) {
+        loop {
+            thread::sleep(POLL_TIME);
+
+            let update = match self.poll() {
+                Ok(false) => continue,
+                Ok(true) => Ok(self.names()),
+                Err(e) => Err(e),
+            };
+            let failed = update.is_err();
+
+            if !report(update) || (failed && !retry(self, report)) {
+                break;
+            }
+        }
+    }
+}
+
+/// Names at the end of `new` that weren't part of `old`, for lists like the ones of a [`Tail`] that
+/// only lose names at the front and gain new ones at the end.
+pub fn appended<'a>(old: &[Name], new: &'a [Name]) -> &'a [Name] {
+    let overlap = (0..=old.len().min(new.len()))
+        .rev()
+        .find(|&len| old[old.len() - len..] == new[..len])
+        .unwrap_or_default();
+    &new[overlap..]
+}
+
+/// Identity of a file, that changes when a file is replaced by another one with the same name.
+#[cfg(unix)]
+fn file_id(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Write};
+
+    use super::{appended, Tail, TailOptions, INITIAL_READ, MAX_LINE};
+    use crate::names::Name;
+
+    #[test]
+    fn follow_rotation_and_alerts() {
+        let path = env::temp_dir().join(format!("rsmatrix-tail-{}.log", std::process::id()));
+        fs::write(&path, "INFO started neo\nERROR lost trinity\npartial").unwrap();
+
+        let options = TailOptions::new(
+            Some(r"(?:started|lost) (\w+)"),
+            &["ERROR".to_owned()],
+            None,
+            Some(3),
+        )
+        .unwrap();
+        let mut tail = Tail::new(&path, options);
+        assert!(tail.poll().unwrap());
+        let texts = |tail: &Tail| tail.names().into_iter().map(|n| n.text).collect::<Vec<_>>();
+
+        assert_eq!(vec!["neo", "trinity"], texts(&tail));
+        assert_eq!(
+            vec![None, Some(TailOptions::DEFAULT_ALERT_COLOR)],
+            tail.names()
+                .into_iter()
+                .map(|n| n.color)
+                .collect::<Vec<_>>()
+        );
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, " started morpheus\nINFO started tank\n").unwrap();
+        assert!(tail.poll().unwrap());
+        assert_eq!(vec!["trinity", "morpheus", "tank"], texts(&tail));
+        assert!(!tail.poll().unwrap());
+
+        // A character that is split between two reads is decoded once the line is complete.
+        file.write_all(b"INFO started ni\xc3").unwrap();
+        assert!(!tail.poll().unwrap());
+        file.write_all(b"\xb1o\n").unwrap();
+        assert!(tail.poll().unwrap());
+        assert_eq!(vec!["morpheus", "tank", "niño"], texts(&tail));
+
+        let before = tail.names();
+        writeln!(file, "ERROR lost switch").unwrap();
+        assert!(tail.poll().unwrap());
+        let names = tail.names();
+        assert_eq!(vec!["tank", "niño", "switch"], texts(&tail));
+        assert_eq!(
+            vec![Name::new("switch".to_owned()).alert(TailOptions::DEFAULT_ALERT_COLOR)],
+            appended(&before, &names)
+        );
+        assert!(appended(&names, &names).is_empty());
+
+        // Rotate the file by replacing it with a new one.
+        let rotated = path.with_extension("log.1");
+        fs::rename(&path, &rotated).unwrap();
+        fs::write(&path, "INFO started dozer\n").unwrap();
+        assert!(tail.poll().unwrap());
+        assert_eq!(vec!["niño", "switch", "dozer"], texts(&tail));
+
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&rotated).unwrap();
+    }
+
+    #[test]
+    fn skip_cut_off_line() {
+        let path = env::temp_dir().join(format!("rsmatrix-cut-{}.log", std::process::id()));
+        let long = "x".repeat(INITIAL_READ as usize);
+        fs::write(&path, format!("started {long}\nstarted neo\n")).unwrap();
+
+        let options = TailOptions::new(Some(r"started (\w+)"), &[], None, None).unwrap();
+        let mut tail = Tail::new(&path, options);
+        assert!(tail.poll().unwrap());
+        assert_eq!(
+            vec!["neo"],
+            tail.names().into_iter().map(|n| n.text).collect::<Vec<_>>()
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn cut_off_long_lines() {
+        let path = env::temp_dir().join(format!("rsmatrix-long-{}.log", std::process::id()));
+        fs::write(&path, "").unwrap();
+
+        let options = TailOptions::new(None, &[], None, None).unwrap();
+        let mut tail = Tail::new(&path, options);
+        assert!(tail.poll().unwrap());
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&[b'x'; MAX_LINE * 2]).unwrap();
+        assert!(!tail.poll().unwrap());
+        assert_eq!(MAX_LINE, tail.partial.len());
+        file.write_all(b"yyy\nstarted neo\n").unwrap();
+        assert!(tail.poll().unwrap());
+
+        assert_eq!(
+            vec!["x".repeat(MAX_LINE), "started neo".to_owned()],
+            tail.names().into_iter().map(|n| n.text).collect::<Vec<_>>()
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+}
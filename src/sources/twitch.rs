@@ -0,0 +1,50 @@
+//! Twitch API to get a list of viewers for single streams.
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use super::NameSource;
+use crate::names::Name;
+
+#[derive(Deserialize)]
+struct Response {
+    chatters: Chatters,
+}
+
+#[derive(Deserialize)]
+struct Chatters {
+    #[serde(default)]
+    broadcaster: Vec<String>,
+    #[serde(default)]
+    vips: Vec<String>,
+    #[serde(default)]
+    moderators: Vec<String>,
+    viewers: Vec<String>,
+}
+
+/// Get a list of currently active viewers for a Twitch username. The broadcaster, VIPs and
+/// moderators are highlighted.
+fn get_viewers(username: &str) -> Result<Vec<Name>> {
+    let url = format!("https://tmi.twitch.tv/group/user/{}/chatters", username);
+    let chatters = ureq::get(&url).call()?.into_json::<Response>()?.chatters;
+
+    Ok(chatters
+        .broadcaster
+        .into_iter()
+        .chain(chatters.vips)
+        .chain(chatters.moderators)
+        .map(|name| Name::new(name).highlighted())
+        .chain(chatters.viewers.into_iter().map(Name::new))
+        .collect())
+}
+
+/// Viewers of a Twitch stream.
+pub struct TwitchSource {
+    pub username: String,
+}
+
+impl NameSource for TwitchSource {
+    fn load(&mut self) -> Result<Vec<Name>> {
+        get_viewers(&self.username)
+    }
+}
@@ -0,0 +1,85 @@
+//! Simple stopwatch that counts up and can be paused, resumed and record lap times.
+
+use std::time::{Duration, Instant};
+
+/// Stopwatch measuring the time it's been running, excluding any time it's been paused.
+pub struct Stopwatch {
+    /// Point in time the stopwatch was last started or resumed, if it's currently running.
+    started: Option<Instant>,
+    /// Time accumulated before the last pause.
+    elapsed: Duration,
+    /// Recorded lap times, each measured from the start of the stopwatch.
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// Create a new stopwatch that immediately starts running.
+    pub fn start() -> Self {
+        Self {
+            started: Some(Instant::now()),
+            elapsed: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+
+    /// Total time the stopwatch has been running.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + self.started.map(|s| s.elapsed()).unwrap_or_default()
+    }
+
+    /// Whether the stopwatch is currently running or paused.
+    pub const fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Pause the stopwatch if it's running, or resume it if it's paused.
+    pub fn toggle(&mut self) {
+        match self.started.take() {
+            Some(started) => self.elapsed += started.elapsed(),
+            None => self.started = Some(Instant::now()),
+        }
+    }
+
+    /// Record the current time as a new lap.
+    pub fn lap(&mut self) {
+        self.laps.push(self.elapsed());
+    }
+
+    /// All recorded laps, oldest first.
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Stopwatch;
+
+    #[test]
+    fn pause_and_laps() {
+        let mut stopwatch = Stopwatch {
+            started: Some(Instant::now() - Duration::from_secs(2)),
+            ..Stopwatch::start()
+        };
+        stopwatch.lap();
+
+        stopwatch.toggle();
+        assert!(!stopwatch.is_running());
+        let paused = stopwatch.elapsed();
+        assert!(paused >= Duration::from_secs(2));
+        assert_eq!(paused, stopwatch.elapsed());
+
+        stopwatch.lap();
+        stopwatch.toggle();
+        assert!(stopwatch.is_running());
+        stopwatch.lap();
+
+        let laps = stopwatch.laps();
+        assert_eq!(3, laps.len());
+        assert!(laps[0] <= laps[1]);
+        assert_eq!(paused, laps[1]);
+        assert!(laps[1] <= laps[2]);
+    }
+}